@@ -0,0 +1,71 @@
+extern crate neuroflow;
+extern crate time;
+
+use neuroflow::FeedForward;
+use neuroflow::data::{DataSet, Extractable};
+use neuroflow::activators;
+use neuroflow::activators::Type;
+
+/*
+    Compare training time of network whose activation function calculates derivative
+    from cached outputs of neurons (built-in types) with the same function which
+    calculates derivative from induced field, i.e. recalculates the function again.
+
+    Run it in release mode:
+        cargo run --release --example benchmark
+*/
+
+const EPOCHS: usize = 100;
+const ROUNDS: usize = 5;
+
+fn train(nn: &mut FeedForward, data: &DataSet) -> f64{
+    let prev = time::now_utc();
+    for _ in 0..EPOCHS{
+        for i in 0..data.len(){
+            let (x, y) = data.get(i);
+            nn.fit(x, y);
+        }
+    }
+    (time::now_utc() - prev).num_microseconds().unwrap() as f64 / 1000.0
+}
+
+fn compare(name: &str, t: Type, func: fn(f64) -> f64, der: fn(f64) -> f64, data: &DataSet){
+    let mut nn = FeedForward::new(&[1, 7, 8, 8, 7, 1]);
+    nn.learning_rate(0.01);
+
+    let (mut cached_time, mut recalculated_time) = (f64::MAX, f64::MAX);
+    let mut deviation: f64 = 0.0;
+
+    // the best of several runs of both networks from the same initial weights
+    for _ in 0..ROUNDS{
+        let mut cached = nn.clone();
+        let mut recalculated = nn.clone();
+        cached.activation(t.clone());
+        recalculated.custom_activation(func, der);
+
+        cached_time = cached_time.min(train(&mut cached, data));
+        recalculated_time = recalculated_time.min(train(&mut recalculated, data));
+
+        for i in 0..data.len(){
+            let x = data.get(i).0;
+            deviation = deviation.max((cached.calc(x)[0] - recalculated.calc(x)[0]).abs());
+        }
+    }
+
+    println!("{:>8}: from output {:>8.3} ms, from argument {:>8.3} ms, speedup {:.2}x, max deviation {:.1e}",
+             name, cached_time, recalculated_time, recalculated_time / cached_time, deviation);
+}
+
+fn main(){
+    let mut data = DataSet::new();
+    let mut i = -3.0;
+    while i <= 2.5 {
+        data.push(&[i], &[0.5*(i.exp().sin()) - (-i.exp()).cos()]);
+        i += 0.05;
+    }
+
+    println!("Network [1, 7, 8, 8, 7, 1], {} epochs of {} samples\n", EPOCHS, data.len());
+
+    compare("tanh", Type::Tanh, activators::tanh, activators::der_tanh, &data);
+    compare("sigmoid", Type::Sigmoid, activators::sigm, activators::der_sigm, &data);
+}
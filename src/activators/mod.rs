@@ -2,17 +2,156 @@
 //! and theirs derivatives
 
 use std::f64;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use math::logistic;
 
 /// Determine types of activation functions contained in this module.
 #[allow(dead_code)]
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum Type {
     Sigmoid,
     Tanh,
     Relu,
-    Custom
+    Custom,
+    LeakyRelu,
+    Elu,
+    Selu,
+    Gelu,
+    Swish,
+    Softplus,
+    Softsign,
+    HardSigmoid,
+    HardTanh,
+    Gaussian,
+    Identity,
+    PRelu,
+    LearnableSwish,
+    /// Custom function registered by `register` under the given name
+    Named(String),
+}
+
+impl Type {
+    /// Initial value of trainable parameter of activation function.
+    ///
+    /// * `return -> Option<f64>` - the value or `None` if function has no parameters.
+    pub fn parameter(&self) -> Option<f64> {
+        match *self {
+            Type::PRelu => Some(PRELU_SLOPE),
+            Type::LearnableSwish => Some(1.0),
+            _ => None,
+        }
+    }
+}
+
+/// Slope of `leaky_relu` for negative arguments
+pub const LEAKY_SLOPE: f64 = 0.01;
+
+/// Activation function of neurons. Implement it in order to use your own
+/// function, e.g. one that captures parameters. Pair of functions or closures
+/// `(value, derivative)` implements this trait as well.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::activators::Activation;
+///
+/// struct Temperature(f64);
+///
+/// impl Activation for Temperature {
+///     fn value(&self, x: f64) -> f64 {
+///         (x / self.0).tanh()
+///     }
+///
+///     fn derivative(&self, x: f64) -> f64 {
+///         (1.0 - (x / self.0).tanh().powi(2)) / self.0
+///     }
+///
+///     fn derivative_from_output(&self, _x: f64, y: f64) -> f64 {
+///         (1.0 - y * y) / self.0
+///     }
+/// }
+///
+/// let mut nn = FeedForward::new(&[1, 3, 1]);
+/// nn.activator(Temperature(2.0));
+///
+/// let slope = 0.2;
+/// nn.custom_activation(move |x| if x > 0.0 {x} else {slope * x},
+///                      move |x| if x > 0.0 {1.0} else {slope});
+/// ```
+pub trait Activation: Send + Sync {
+    /// Value of function at `x`
+    fn value(&self, x: f64) -> f64;
+
+    /// Derivative of function at `x`
+    fn derivative(&self, x: f64) -> f64;
+
+    /// Derivative of function at `x` expressed by its already calculated
+    /// value `y`, which is cheaper for many functions. By default it is `derivative(x)`.
+    fn derivative_from_output(&self, x: f64, _y: f64) -> f64 {
+        self.derivative(x)
+    }
+}
+
+impl<F, D> Activation for (F, D) where F: Fn(f64) -> f64 + Send + Sync, D: Fn(f64) -> f64 + Send + Sync {
+    fn value(&self, x: f64) -> f64 {
+        (self.0)(x)
+    }
+
+    fn derivative(&self, x: f64) -> f64 {
+        (self.1)(x)
+    }
+}
+
+fn registry() -> &'static RwLock<HashMap<String, Arc<dyn Activation>>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, Arc<dyn Activation>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Register custom activation function under the given name, so network that
+/// uses `Type::Named(name)` can be saved and restored by `neuroflow::io`.
+/// Function must be registered in each program that loads such network.
+/// Registration with already used name replaces the previous function.
+///
+/// * `name: &str` - name of function;
+/// * `activation: A` - activation function, e.g. pair of function and its derivative.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::activators::{self, Type};
+///
+/// fn cube(x: f64) -> f64 { x.powi(3) }
+/// fn der_cube(x: f64) -> f64 { 3.0 * x.powi(2) }
+///
+/// activators::register("cube", (cube, der_cube));
+///
+/// let mut nn = FeedForward::new(&[1, 3, 1]);
+/// nn.activation(Type::Named("cube".to_string()));
+/// ```
+pub fn register<A>(name: &str, activation: A) where A: Activation + 'static {
+    registry().write().unwrap().insert(name.to_string(), Arc::new(activation));
+}
+
+/// Find registered activation function.
+///
+/// * `name: &str` - name of function;
+/// * `return -> Option<Arc<dyn Activation>>` - the function or `None`
+///   if there is no function with such name.
+pub fn registered(name: &str) -> Option<Arc<dyn Activation>>{
+    registry().read().unwrap().get(name).cloned()
 }
 
+/// Initial slope of `prelu` for negative arguments
+pub const PRELU_SLOPE: f64 = 0.25;
+
+/// Parameters of `selu` for self-normalizing networks
+pub const SELU_ALPHA: f64 = 1.6732632423543772;
+pub const SELU_LAMBDA: f64 = 1.0507009873554805;
+
 
 pub fn sigm(x: f64) -> f64{ 1.0/(1.0 + x.exp()) }
 pub fn der_sigm(x: f64) -> f64{
@@ -37,3 +176,223 @@ pub fn der_relu(x: f64) -> f64{
         1.0
     }
 }
+
+pub fn leaky_relu(x: f64) -> f64{
+    if x <= 0.0 {
+        LEAKY_SLOPE * x
+    } else {
+        x
+    }
+}
+pub fn der_leaky_relu(x: f64) -> f64{
+    if x <= 0.0 {
+        LEAKY_SLOPE
+    } else {
+        1.0
+    }
+}
+
+pub fn elu(x: f64) -> f64{
+    if x <= 0.0 {
+        x.exp_m1()
+    } else {
+        x
+    }
+}
+pub fn der_elu(x: f64) -> f64{
+    if x <= 0.0 {
+        x.exp()
+    } else {
+        1.0
+    }
+}
+
+pub fn selu(x: f64) -> f64{
+    if x <= 0.0 {
+        SELU_LAMBDA * SELU_ALPHA * x.exp_m1()
+    } else {
+        SELU_LAMBDA * x
+    }
+}
+pub fn der_selu(x: f64) -> f64{
+    if x <= 0.0 {
+        SELU_LAMBDA * SELU_ALPHA * x.exp()
+    } else {
+        SELU_LAMBDA
+    }
+}
+
+/// Gaussian error linear unit in its `tanh` approximation
+pub fn gelu(x: f64) -> f64{
+    let k = (2.0 / f64::consts::PI).sqrt();
+    0.5 * x * (1.0 + (k * (x + 0.044715 * x.powi(3))).tanh())
+}
+pub fn der_gelu(x: f64) -> f64{
+    let k = (2.0 / f64::consts::PI).sqrt();
+    let t = (k * (x + 0.044715 * x.powi(3))).tanh();
+    0.5 * (1.0 + t) + 0.5 * x * (1.0 - t * t) * k * (1.0 + 3.0 * 0.044715 * x * x)
+}
+
+/// Swish, also known as SiLU: `x * logistic(x)`
+pub fn swish(x: f64) -> f64{
+    x * logistic(x)
+}
+pub fn der_swish(x: f64) -> f64{
+    let s = logistic(x);
+    s + x * s * (1.0 - s)
+}
+
+pub fn softplus(x: f64) -> f64{
+    f64::max(0.0, x) + (-x.abs()).exp().ln_1p()
+}
+pub fn der_softplus(x: f64) -> f64{
+    logistic(x)
+}
+
+pub fn softsign(x: f64) -> f64{
+    x / (1.0 + x.abs())
+}
+pub fn der_softsign(x: f64) -> f64{
+    1.0 / (1.0 + x.abs()).powi(2)
+}
+
+pub fn hard_sigm(x: f64) -> f64{
+    (0.2 * x + 0.5).clamp(0.0, 1.0)
+}
+pub fn der_hard_sigm(x: f64) -> f64{
+    if x > -2.5 && x < 2.5 {
+        0.2
+    } else {
+        0.0
+    }
+}
+
+pub fn hard_tanh(x: f64) -> f64{
+    x.clamp(-1.0, 1.0)
+}
+pub fn der_hard_tanh(x: f64) -> f64{
+    if x > -1.0 && x < 1.0 {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+pub fn gaussian(x: f64) -> f64{
+    (-x * x).exp()
+}
+pub fn der_gaussian(x: f64) -> f64{
+    -2.0 * x * (-x * x).exp()
+}
+
+pub fn identity(x: f64) -> f64{
+    x
+}
+pub fn der_identity(_x: f64) -> f64{
+    1.0
+}
+
+/* Derivatives expressed by output `y` of activation function. They allow
+   not to calculate function again during back propagation. */
+
+pub fn der_sigm_output(y: f64) -> f64{
+    y*(1.0 - y)
+}
+
+pub fn der_tanh_output(y: f64) -> f64{
+    1.0 - y * y
+}
+
+pub fn der_relu_output(y: f64) -> f64{
+    if y <= 0.0 {
+        0.0
+    } else {
+        1.0
+    }
+}
+
+pub fn der_leaky_relu_output(y: f64) -> f64{
+    if y <= 0.0 {
+        LEAKY_SLOPE
+    } else {
+        1.0
+    }
+}
+
+pub fn der_elu_output(y: f64) -> f64{
+    if y <= 0.0 {
+        y + 1.0
+    } else {
+        1.0
+    }
+}
+
+pub fn der_selu_output(y: f64) -> f64{
+    if y <= 0.0 {
+        y + SELU_LAMBDA * SELU_ALPHA
+    } else {
+        SELU_LAMBDA
+    }
+}
+
+pub fn der_softsign_output(y: f64) -> f64{
+    (1.0 - y.abs()).powi(2)
+}
+
+pub fn der_hard_sigm_output(y: f64) -> f64{
+    if y > 0.0 && y < 1.0 {
+        0.2
+    } else {
+        0.0
+    }
+}
+
+pub fn der_hard_tanh_output(y: f64) -> f64{
+    if y > -1.0 && y < 1.0 {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+pub fn der_identity_output(_y: f64) -> f64{
+    1.0
+}
+
+/// Parametric ReLU with trainable slope `a` for negative arguments
+pub fn prelu(x: f64, a: f64) -> f64{
+    if x <= 0.0 {
+        a * x
+    } else {
+        x
+    }
+}
+pub fn der_prelu(x: f64, a: f64) -> f64{
+    if x <= 0.0 {
+        a
+    } else {
+        1.0
+    }
+}
+/// Derivative of `prelu` by its parameter
+pub fn der_prelu_param(x: f64, _a: f64) -> f64{
+    if x <= 0.0 {
+        x
+    } else {
+        0.0
+    }
+}
+
+/// Swish with trainable parameter: `x * logistic(b * x)`
+pub fn swish_beta(x: f64, b: f64) -> f64{
+    x * logistic(b * x)
+}
+pub fn der_swish_beta(x: f64, b: f64) -> f64{
+    let s = logistic(b * x);
+    s + b * x * s * (1.0 - s)
+}
+/// Derivative of `swish_beta` by its parameter
+pub fn der_swish_beta_param(x: f64, b: f64) -> f64{
+    let s = logistic(b * x);
+    x * x * s * (1.0 - s)
+}
@@ -0,0 +1,564 @@
+//! Module contains self-attention layers and transformer encoder
+//! for sequence classification and regression.
+//!
+//! `Transformer` projects each vector of sequence to the model dimension, adds
+//! sinusoidal positional encoding and passes the sequence through the stack of
+//! encoder blocks. Each block consists of scaled dot-product multi-head
+//! self-attention and position-wise feed forward layer, both wrapped by residual
+//! connection and layer normalization. The encoded sequence is averaged and passed
+//! to `FeedForward` network which produces the output.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::attention::Transformer;
+//! use neuroflow::data::SequenceSet;
+//!
+//! /*
+//!     Inputs of length 2 are projected to model dimension 8. There are 2 attention heads,
+//!     single encoder block with 16 neurons in feed forward layer,
+//!     and the head network [8, 4, 2] produces two outputs.
+//! */
+//! let mut nn = Transformer::new(2, 8, 2, 1, 16, &[4, 2]);
+//!
+//! let mut data = SequenceSet::new();
+//! data.push(&[vec![0.1, 0.2], vec![0.3, 0.4], vec![0.5, 0.6]], &[vec![1.0, 0.0]]);
+//! data.push(&[vec![0.6, 0.5], vec![0.4, 0.3], vec![0.2, 0.1]], &[vec![0.0, 1.0]]);
+//!
+//! nn.learning_rate(0.01)
+//!     .train(&data, 500);
+//!
+//! let res = nn.calc(&[vec![0.1, 0.2], vec![0.3, 0.4]]);
+//! ```
+
+use math;
+use activators;
+use data::SequenceExtractable;
+
+use FeedForward;
+use Transform;
+use ErrorKind;
+
+type Matrix = Vec<Vec<f64>>;
+
+/// Small constant that prevents division by zero in layer normalization
+const EPSILON: f64 = 1e-5;
+
+/// Sinusoidal positional encoding.
+///
+/// * `len: usize` - length of sequence;
+/// * `dim: usize` - dimension of vectors;
+/// * `return -> Vec<Vec<f64>>` - matrix where row `t` is the encoding of position `t`.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::attention::positional_encoding;
+///
+/// let pe = positional_encoding(10, 4);
+/// assert_eq!(pe[0], vec![0.0, 1.0, 0.0, 1.0]);
+/// ```
+#[allow(clippy::needless_range_loop)]
+pub fn positional_encoding(len: usize, dim: usize) -> Vec<Vec<f64>> {
+    let mut pe = math::zeros(len, dim);
+    for t in 0..len{
+        for i in 0..dim{
+            let angle = t as f64 / 10_000f64.powf((i - i % 2) as f64 / dim as f64);
+            pe[t][i] = if i % 2 == 0 { angle.sin() } else { angle.cos() };
+        }
+    }
+    pe
+}
+
+/// Fully connected linear layer applied to each vector of sequence.
+/// The first column of weights is bias.
+#[derive(Serialize, Deserialize)]
+struct Dense {
+    w: Matrix,
+
+    #[serde(skip_deserializing, skip_serializing)]
+    g: Matrix,
+}
+
+impl Dense {
+    fn new(outputs: usize, inputs: usize) -> Dense {
+        Dense {w: math::random(outputs, inputs + 1, 1.0 / ((inputs + 1) as f64).sqrt()), g: Vec::new()}
+    }
+
+    fn forward(&self, xs: &Matrix) -> Matrix {
+        xs.iter().map(|x| self.w.iter().map(|row| row[0] + math::dot(&row[1..], x)).collect()).collect()
+    }
+
+    /// Accumulate gradient of weights and return gradient by inputs
+    #[allow(clippy::needless_range_loop)]
+    fn backward(&mut self, xs: &Matrix, dys: &Matrix) -> Matrix {
+        if self.g.is_empty() {
+            self.g = math::zeros(self.w.len(), self.w[0].len());
+        }
+
+        let mut dxs = math::zeros(xs.len(), self.w[0].len() - 1);
+        for t in 0..xs.len(){
+            for i in 0..self.w.len(){
+                let dy = dys[t][i];
+                self.g[i][0] += dy;
+                for k in 0..xs[t].len(){
+                    self.g[i][k + 1] += dy * xs[t][k];
+                    dxs[t][k] += dy * self.w[i][k + 1];
+                }
+            }
+        }
+        dxs
+    }
+
+    fn apply(&mut self, rate: f64){
+        for i in 0..self.g.len(){
+            for k in 0..self.g[i].len(){
+                self.w[i][k] -= rate * self.g[i][k];
+                self.g[i][k] = 0.0;
+            }
+        }
+    }
+}
+
+/// Values of layer normalization cached on the forward pass
+struct NormCache {
+    xhat: Matrix,
+    sigma: Vec<f64>,
+}
+
+/// Layer normalization with trainable scale `gamma` and shift `beta`
+#[derive(Serialize, Deserialize)]
+struct LayerNorm {
+    gamma: Vec<f64>,
+    beta: Vec<f64>,
+
+    #[serde(skip_deserializing, skip_serializing)]
+    dgamma: Vec<f64>,
+    #[serde(skip_deserializing, skip_serializing)]
+    dbeta: Vec<f64>,
+}
+
+impl LayerNorm {
+    fn new(dim: usize) -> LayerNorm {
+        LayerNorm {gamma: vec![1.0; dim], beta: vec![0.0; dim], dgamma: Vec::new(), dbeta: Vec::new()}
+    }
+
+    fn forward(&self, xs: &Matrix) -> (Matrix, NormCache) {
+        let mut cache = NormCache {xhat: Vec::new(), sigma: Vec::new()};
+        let mut ys = Vec::new();
+
+        for x in xs.iter(){
+            let n = x.len() as f64;
+            let mean = x.iter().sum::<f64>() / n;
+            let var = x.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+            let sigma = (var + EPSILON).sqrt();
+
+            let xhat: Vec<f64> = x.iter().map(|v| (v - mean) / sigma).collect();
+            ys.push((0..x.len()).map(|i| self.gamma[i] * xhat[i] + self.beta[i]).collect());
+
+            cache.xhat.push(xhat);
+            cache.sigma.push(sigma);
+        }
+        (ys, cache)
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn backward(&mut self, cache: &NormCache, dys: &Matrix) -> Matrix {
+        let dim = self.gamma.len();
+        if self.dgamma.is_empty() {
+            self.dgamma = vec![0.0; dim];
+            self.dbeta = vec![0.0; dim];
+        }
+
+        let mut dxs = Vec::new();
+        for t in 0..dys.len(){
+            let xhat = &cache.xhat[t];
+            let mut dxhat = vec![0.0; dim];
+            for i in 0..dim{
+                self.dgamma[i] += dys[t][i] * xhat[i];
+                self.dbeta[i] += dys[t][i];
+                dxhat[i] = dys[t][i] * self.gamma[i];
+            }
+
+            let mean = dxhat.iter().sum::<f64>() / dim as f64;
+            let mean_x = math::dot(&dxhat, xhat) / dim as f64;
+            dxs.push((0..dim).map(|i| (dxhat[i] - mean - xhat[i] * mean_x) / cache.sigma[t]).collect());
+        }
+        dxs
+    }
+
+    fn apply(&mut self, rate: f64){
+        for i in 0..self.dgamma.len(){
+            self.gamma[i] -= rate * self.dgamma[i];
+            self.beta[i] -= rate * self.dbeta[i];
+            self.dgamma[i] = 0.0;
+            self.dbeta[i] = 0.0;
+        }
+    }
+}
+
+/// Values of multi-head attention cached on the forward pass
+struct AttentionCache {
+    q: Matrix,
+    k: Matrix,
+    v: Matrix,
+    scores: Vec<Matrix>,
+    o: Matrix,
+}
+
+/// Scaled dot-product multi-head self-attention
+#[derive(Serialize, Deserialize)]
+struct MultiHeadAttention {
+    heads: usize,
+    wq: Dense,
+    wk: Dense,
+    wv: Dense,
+    wo: Dense,
+}
+
+impl MultiHeadAttention {
+    fn new(dim: usize, heads: usize) -> MultiHeadAttention {
+        assert_eq!(dim % heads, 0, "model dimension must be divisible by amount of heads");
+        MultiHeadAttention {heads, wq: Dense::new(dim, dim), wk: Dense::new(dim, dim),
+            wv: Dense::new(dim, dim), wo: Dense::new(dim, dim)}
+    }
+
+    fn forward(&self, xs: &Matrix) -> (Matrix, AttentionCache) {
+        let (q, k, v) = (self.wq.forward(xs), self.wk.forward(xs), self.wv.forward(xs));
+        let (len, dim) = (xs.len(), q[0].len());
+        let size = dim / self.heads;
+        let scale = 1.0 / (size as f64).sqrt();
+
+        let mut o = math::zeros(len, dim);
+        let mut scores = Vec::new();
+        for h in 0..self.heads{
+            let r = h * size..(h + 1) * size;
+            let mut a = math::zeros(len, len);
+            for i in 0..len{
+                for j in 0..len{
+                    a[i][j] = scale * math::dot(&q[i][r.clone()], &k[j][r.clone()]);
+                }
+                softmax(&mut a[i]);
+                for j in 0..len{
+                    for c in r.clone(){
+                        o[i][c] += a[i][j] * v[j][c];
+                    }
+                }
+            }
+            scores.push(a);
+        }
+
+        let y = self.wo.forward(&o);
+        (y, AttentionCache {q, k, v, scores, o})
+    }
+
+    fn backward(&mut self, xs: &Matrix, cache: &AttentionCache, dys: &Matrix) -> Matrix {
+        let (len, dim) = (xs.len(), cache.q[0].len());
+        let size = dim / self.heads;
+        let scale = 1.0 / (size as f64).sqrt();
+
+        let d_o = self.wo.backward(&cache.o, dys);
+        let mut dq = math::zeros(len, dim);
+        let mut dk = math::zeros(len, dim);
+        let mut dv = math::zeros(len, dim);
+
+        for h in 0..self.heads{
+            let r = h * size..(h + 1) * size;
+            let a = &cache.scores[h];
+            for i in 0..len{
+                // Gradient by attention weights and then by scores through softmax
+                let da: Vec<f64> = (0..len).map(|j| math::dot(&d_o[i][r.clone()], &cache.v[j][r.clone()])).collect();
+                let s = math::dot(&a[i], &da);
+                for j in 0..len{
+                    let ds = a[i][j] * (da[j] - s) * scale;
+                    for c in r.clone(){
+                        dv[j][c] += a[i][j] * d_o[i][c];
+                        dq[i][c] += ds * cache.k[j][c];
+                        dk[j][c] += ds * cache.q[i][c];
+                    }
+                }
+            }
+        }
+
+        let mut dxs = self.wq.backward(xs, &dq);
+        add(&mut dxs, &self.wk.backward(xs, &dk));
+        add(&mut dxs, &self.wv.backward(xs, &dv));
+        dxs
+    }
+
+    fn apply(&mut self, rate: f64){
+        self.wq.apply(rate);
+        self.wk.apply(rate);
+        self.wv.apply(rate);
+        self.wo.apply(rate);
+    }
+}
+
+/// Values of encoder block cached on the forward pass
+struct BlockCache {
+    x: Matrix,
+    attention: AttentionCache,
+    norm1: NormCache,
+    u: Matrix,
+    v: Matrix,
+    h: Matrix,
+    norm2: NormCache,
+}
+
+/// Encoder block: `u = LayerNorm(x + Attention(x))`, `y = LayerNorm(u + FF(u))`
+#[derive(Serialize, Deserialize)]
+struct EncoderBlock {
+    attention: MultiHeadAttention,
+    norm1: LayerNorm,
+    ff1: Dense,
+    ff2: Dense,
+    norm2: LayerNorm,
+}
+
+impl EncoderBlock {
+    fn new(dim: usize, heads: usize, ff: usize) -> EncoderBlock {
+        EncoderBlock {attention: MultiHeadAttention::new(dim, heads), norm1: LayerNorm::new(dim),
+            ff1: Dense::new(ff, dim), ff2: Dense::new(dim, ff), norm2: LayerNorm::new(dim)}
+    }
+
+    fn forward(&self, xs: &Matrix) -> (Matrix, BlockCache) {
+        let (mut a, attention) = self.attention.forward(xs);
+        add(&mut a, xs);
+        let (u, norm1) = self.norm1.forward(&a);
+
+        let v = self.ff1.forward(&u);
+        let h: Matrix = v.iter().map(|r| r.iter().map(|x| activators::relu(*x)).collect()).collect();
+        let mut f = self.ff2.forward(&h);
+        add(&mut f, &u);
+        let (y, norm2) = self.norm2.forward(&f);
+
+        (y, BlockCache {x: xs.clone(), attention, norm1, u, v, h, norm2})
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn backward(&mut self, cache: &BlockCache, dys: &Matrix) -> Matrix {
+        let df = self.norm2.backward(&cache.norm2, dys);
+        let mut dh = self.ff2.backward(&cache.h, &df);
+        for t in 0..dh.len(){
+            for i in 0..dh[t].len(){
+                dh[t][i] *= activators::der_relu(cache.v[t][i]);
+            }
+        }
+        let mut du = self.ff1.backward(&cache.u, &dh);
+        add(&mut du, &df);
+
+        let da = self.norm1.backward(&cache.norm1, &du);
+        let mut dx = self.attention.backward(&cache.x, &cache.attention, &da);
+        add(&mut dx, &da);
+        dx
+    }
+
+    fn apply(&mut self, rate: f64){
+        self.attention.apply(rate);
+        self.norm1.apply(rate);
+        self.ff1.apply(rate);
+        self.ff2.apply(rate);
+        self.norm2.apply(rate);
+    }
+}
+
+/// Transformer encoder for sequence classification and regression.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::attention::Transformer;
+///
+/// let mut nn = Transformer::new(3, 8, 2, 2, 16, &[2]);
+///
+/// nn.fit(&[vec![0.1, 0.2, 0.3], vec![0.3, 0.2, 0.1]], &[1.0, 0.0]);
+/// let res: Vec<f64> = nn.calc(&[vec![0.1, 0.2, 0.3]]).to_vec();
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct Transformer {
+    input: Dense,
+    blocks: Vec<EncoderBlock>,
+    head: FeedForward,
+}
+
+impl Transformer {
+    /// The constructor of `Transformer` struct
+    ///
+    /// * `input: usize` - length of vectors in input sequence;
+    /// * `model: usize` - model dimension, i.e. length of vectors inside of encoder.
+    ///   It must be divisible by `heads`;
+    /// * `heads: usize` - amount of attention heads;
+    /// * `blocks: usize` - amount of encoder blocks;
+    /// * `ff: usize` - amount of neurons in hidden layer of position-wise feed forward layer;
+    /// * `head: &[i32]` - architecture of `FeedForward` network that is applied to the
+    ///   averaged encoded sequence, without input layer. The last element is amount of outputs;
+    /// * `return` - `Transformer` struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neuroflow::attention::Transformer;
+    /// let mut nn = Transformer::new(3, 8, 2, 2, 16, &[4, 2]);
+    /// ```
+    pub fn new(input: usize, model: usize, heads: usize, blocks: usize, ff: usize, head: &[i32]) -> Transformer {
+        let mut arch = vec![model as i32];
+        arch.extend_from_slice(head);
+
+        Transformer {
+            input: Dense::new(model, input),
+            blocks: (0..blocks).map(|_| EncoderBlock::new(model, heads, ff)).collect(),
+            head: FeedForward::new(&arch),
+        }
+    }
+
+    fn encode(&self, xs: &[Vec<f64>]) -> (Matrix, Vec<BlockCache>) {
+        assert!(!xs.is_empty(), "sequence must contain at least one vector");
+
+        let mut z = self.input.forward(&xs.to_vec());
+        let pe = positional_encoding(xs.len(), z[0].len());
+        add(&mut z, &pe);
+
+        let mut caches = Vec::new();
+        for block in self.blocks.iter(){
+            let (y, cache) = block.forward(&z);
+            caches.push(cache);
+            z = y;
+        }
+        (z, caches)
+    }
+
+    /// Vector `[1, mean(z)]` that is passed to the head network
+    fn pool(z: &Matrix) -> Vec<f64> {
+        let mut p = vec![0.0; z[0].len() + 1];
+        p[0] = 1.0;
+        for t in 0..z.len(){
+            for i in 0..z[t].len(){
+                p[i + 1] += z[t][i] / z.len() as f64;
+            }
+        }
+        p
+    }
+
+    /// Train network by bulked sequences. The last vector of desired output
+    /// sequence is used as the target.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::SequenceExtractable` trait;
+    /// * `iterations: i64` - iterations count.
+    pub fn train<T>(&mut self, data: &T, iterations: i64) where T: SequenceExtractable{
+        for _ in 0..iterations{
+            let (x, y) = data.rand();
+            self.fit(x, &y[y.len() - 1]);
+        }
+    }
+
+    /// Train network by single sequence.
+    ///
+    /// * `xs: &[Vec<f64>]` - sequence of input vectors;
+    /// * `d: &[f64]` - expected output.
+    ///
+    /// # Panics
+    ///
+    /// If sequence is empty.
+    pub fn fit(&mut self, xs: &[Vec<f64>], d: &[f64]){
+        let (z, caches) = self.encode(xs);
+        let p = Transformer::pool(&z);
+
+        self.head.forward(&p);
+        self.head.backward(d);
+        let delta = self.head.input_delta();
+        self.head.update(&p);
+
+        // Deltas of head have opposite sign to gradient
+        let len = z.len() as f64;
+        let mut dz: Matrix = vec![delta.iter().map(|v| -v / len).collect(); z.len()];
+        for (block, cache) in self.blocks.iter_mut().zip(caches.iter()).rev(){
+            dz = block.backward(cache, &dz);
+        }
+        self.input.backward(&xs.to_vec(), &dz);
+
+        let rate = self.head.learn_rate;
+        self.input.apply(rate);
+        for block in self.blocks.iter_mut(){
+            block.apply(rate);
+        }
+    }
+
+    /// Calculate the response by trained network.
+    ///
+    /// * `xs: &[Vec<f64>]` - sequence of input vectors;
+    /// * `return -> &[f64]` - slice of calculated data.
+    ///
+    /// # Panics
+    ///
+    /// If sequence is empty.
+    pub fn calc(&mut self, xs: &[Vec<f64>]) -> &[f64]{
+        let (z, _) = self.encode(xs);
+        let p = Transformer::pool(&z);
+
+        self.head.forward(&p);
+        &self.head.layers[self.head.layers.len() - 1].y
+    }
+
+    /// Choose activation function of head network.
+    ///
+    /// * `func: neuroflow::activators::Type` - enum element that indicates which
+    ///   function to use;
+    /// * `return -> &mut Transformer` - link on the current struct.
+    pub fn activation(&mut self, func: activators::Type) -> &mut Transformer {
+        self.head.activation(func);
+        self
+    }
+
+    /// Set the learning rate of network.
+    ///
+    /// * `learning_rate: f64` - learning rate;
+    /// * `return -> &mut Transformer` - link on the current struct.
+    pub fn learning_rate(&mut self, learning_rate: f64) -> &mut Transformer {
+        self.head.learning_rate(learning_rate);
+        self
+    }
+
+    /// Set the momentum of head network.
+    ///
+    /// * `momentum: f64` - momentum;
+    /// * `return -> &mut Transformer` - link on the current struct.
+    pub fn momentum(&mut self, momentum: f64) -> &mut Transformer {
+        self.head.momentum(momentum);
+        self
+    }
+
+    /// Get current training error
+    ///
+    /// * `return -> f64` - training error
+    pub fn get_error(&self) -> f64 {
+        self.head.get_error()
+    }
+}
+
+impl Transform for Transformer{
+    fn after(&mut self) -> Result<(), ErrorKind>{
+        self.head.after()
+    }
+}
+
+/// Softmax of vector in place
+fn softmax(x: &mut [f64]){
+    let max = x.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let mut sum = 0.0;
+    for v in x.iter_mut(){
+        *v = (*v - max).exp();
+        sum += *v;
+    }
+    for v in x.iter_mut(){
+        *v /= sum;
+    }
+}
+
+/// Element-wise addition of matrices in place
+fn add(a: &mut Matrix, b: &Matrix){
+    for t in 0..a.len(){
+        for i in 0..a[t].len(){
+            a[t][i] += b[t][i];
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Module contains autoencoder neural network and helpers for anomaly detection.
+//!
+//! Autoencoder is `FeedForward` network which is trained to reproduce its input.
+//! It consists of encoder that compresses input vector to the code in bottleneck
+//! layer and mirrored decoder that restores input from the code.
+//! Samples that are badly reconstructed are considered as anomalies.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::autoencoder::Autoencoder;
+//! use neuroflow::data::DataSet;
+//!
+//! /* Encoder [3, 2, 1] is mirrored, so the whole network is [3, 2, 1, 2, 3] */
+//! let mut ae = Autoencoder::new(&[3, 2, 1]);
+//!
+//! let mut data = DataSet::new();
+//! let mut t = -0.5;
+//! while t <= 0.5 {
+//!     data.push(&[t, 0.5 * t, -t], &[]);
+//!     t += 0.05;
+//! }
+//!
+//! ae.learning_rate(0.01)
+//!     .train(&data, 10_000);
+//!
+//! /* Threshold is the error that is not exceeded by 99% of samples in the set */
+//! ae.calibrate(&data, 0.99);
+//!
+//! let code = ae.encode(&[0.2, 0.1, -0.2]);
+//! let restored = ae.decode(&code);
+//! let is_anomaly = ae.is_anomaly(&[0.4, -0.4, 0.4]);
+//! ```
+
+use activators;
+use data::Extractable;
+
+use FeedForward;
+use Transform;
+use ErrorKind;
+
+/// Autoencoder neural network.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::autoencoder::Autoencoder;
+///
+/// let mut ae = Autoencoder::new(&[4, 2]);
+/// ae.fit(&[0.1, 0.2, 0.3, 0.4]);
+///
+/// let error = ae.reconstruction_error(&[0.1, 0.2, 0.3, 0.4]);
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct Autoencoder {
+    nn: FeedForward,
+    code: usize,
+    threshold: Option<f64>,
+}
+
+impl Autoencoder {
+    /// The constructor of `Autoencoder` struct
+    ///
+    /// * `architecture: &[i32]` - the architecture of encoder where the first element is
+    ///   length of input vector and the last one is length of code. Decoder has the mirrored
+    ///   architecture;
+    /// * `return` - `Autoencoder` struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neuroflow::autoencoder::Autoencoder;
+    /// let mut ae = Autoencoder::new(&[10, 6, 3]);
+    /// ```
+    pub fn new(architecture: &[i32]) -> Autoencoder {
+        assert!(architecture.len() >= 2, "encoder must have at least input and code layers");
+
+        let mut arch = architecture.to_vec();
+        arch.extend(architecture.iter().rev().skip(1));
+
+        Autoencoder {nn: FeedForward::new(&arch), code: architecture.len() - 2, threshold: None}
+    }
+
+    /// Train autoencoder by bulked data. Only input vectors of data are used.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `iterations: i64` - iterations count.
+    pub fn train<T>(&mut self, data: &T, iterations: i64) where T: Extractable{
+        for _ in 0..iterations{
+            let (x, _) = data.rand();
+            self.fit(x);
+        }
+    }
+
+    /// Train autoencoder by single vector.
+    ///
+    /// * `x: &[f64]` - input vector which is also the expected output.
+    pub fn fit(&mut self, x: &[f64]){
+        self.nn.fit(x, x);
+    }
+
+    /// Calculate the reconstruction of input vector.
+    ///
+    /// * `x: &[f64]` - input vector;
+    /// * `return -> &[f64]` - reconstructed vector.
+    pub fn calc(&mut self, x: &[f64]) -> &[f64]{
+        self.nn.calc(x)
+    }
+
+    /// Compress input vector to the code.
+    ///
+    /// * `x: &[f64]` - input vector;
+    /// * `return -> Vec<f64>` - output of bottleneck layer.
+    pub fn encode(&mut self, x: &[f64]) -> Vec<f64>{
+        self.nn.calc(x);
+        self.nn.layers[self.code].y.clone()
+    }
+
+    /// Restore vector from the code.
+    ///
+    /// * `code: &[f64]` - code of vector;
+    /// * `return -> Vec<f64>` - decoded vector.
+    pub fn decode(&mut self, code: &[f64]) -> Vec<f64>{
+        assert_eq!(code.len(), self.nn.layers[self.code].y.len(), "wrong length of code");
+
+        self.nn.layers[self.code].y = code.to_vec();
+        self.nn.propagate(self.code + 1);
+        self.nn.layers[self.nn.layers.len() - 1].y.clone()
+    }
+
+    /// Mean squared error between input vector and its reconstruction.
+    ///
+    /// * `x: &[f64]` - input vector;
+    /// * `return -> f64` - reconstruction error.
+    pub fn reconstruction_error(&mut self, x: &[f64]) -> f64{
+        let res = self.nn.calc(x);
+        let mut error = 0.0;
+        for i in 0..x.len(){
+            error += (x[i] - res[i]).powi(2);
+        }
+        error / x.len() as f64
+    }
+
+    /// Reconstruction errors of each input vector of data.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `return -> Vec<f64>` - reconstruction error of each sample.
+    pub fn errors<T>(&mut self, data: &T) -> Vec<f64> where T: Extractable{
+        (0..data.len()).map(|i| self.reconstruction_error(data.get(i).0)).collect()
+    }
+
+    /// Set anomaly threshold as the quantile of reconstruction errors of the data.
+    /// The data is supposed to contain normal samples only.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `quantile: f64` - share of samples of data whose error must not exceed the
+    ///   threshold, e.g. `0.99`;
+    /// * `return -> f64` - calibrated threshold.
+    pub fn calibrate<T>(&mut self, data: &T, quantile: f64) -> f64 where T: Extractable{
+        assert!(data.len() > 0, "data for calibration is empty");
+        assert!(quantile > 0.0 && quantile <= 1.0, "quantile must be in range (0, 1]");
+
+        let mut errors = self.errors(data);
+        errors.sort_by(f64::total_cmp);
+
+        let i = ((quantile * errors.len() as f64).ceil() as usize).max(1) - 1;
+        self.threshold = Some(errors[i]);
+        errors[i]
+    }
+
+    /// Get anomaly threshold. It is `None` until `calibrate` or `set_threshold` is called.
+    pub fn threshold(&self) -> Option<f64>{
+        self.threshold
+    }
+
+    /// Set anomaly threshold manually.
+    ///
+    /// * `threshold: f64` - maximal reconstruction error of normal sample;
+    /// * `return -> &mut Autoencoder` - link on the current struct.
+    pub fn set_threshold(&mut self, threshold: f64) -> &mut Autoencoder {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Check whether vector is anomaly, i.e. its reconstruction error exceeds threshold.
+    ///
+    /// * `x: &[f64]` - input vector;
+    /// * `return -> bool` - `true` if vector is anomaly.
+    ///
+    /// # Panics
+    ///
+    /// If threshold is neither calibrated nor set.
+    pub fn is_anomaly(&mut self, x: &[f64]) -> bool{
+        let threshold = self.threshold.expect("threshold is not set, call `calibrate` first");
+        self.reconstruction_error(x) > threshold
+    }
+
+    /// Choose activation function.
+    ///
+    /// * `func: neuroflow::activators::Type` - enum element that indicates which
+    ///   function to use;
+    /// * `return -> &mut Autoencoder` - link on the current struct.
+    pub fn activation(&mut self, func: activators::Type) -> &mut Autoencoder {
+        self.nn.activation(func);
+        self
+    }
+
+    /// Set the learning rate of network.
+    ///
+    /// * `learning_rate: f64` - learning rate;
+    /// * `return -> &mut Autoencoder` - link on the current struct.
+    pub fn learning_rate(&mut self, learning_rate: f64) -> &mut Autoencoder {
+        self.nn.learning_rate(learning_rate);
+        self
+    }
+
+    /// Set the momentum of network.
+    ///
+    /// * `momentum: f64` - momentum;
+    /// * `return -> &mut Autoencoder` - link on the current struct.
+    pub fn momentum(&mut self, momentum: f64) -> &mut Autoencoder {
+        self.nn.momentum(momentum);
+        self
+    }
+
+    /// Get current training error
+    ///
+    /// * `return -> f64` - training error
+    pub fn get_error(&self) -> f64 {
+        self.nn.get_error()
+    }
+}
+
+impl Transform for Autoencoder{
+    fn after(&mut self) -> Result<(), ErrorKind>{
+        self.nn.after()
+    }
+}
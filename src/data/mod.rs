@@ -5,10 +5,17 @@
 //! When you load data from file, it'll be placed into `DataSet`.
 use std;
 
-use rand::{thread_rng, Rng};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use rand::{thread_rng, Rng, SeedableRng};
+use rand::rngs::StdRng;
 use rand::distributions::Uniform;
+use rand::seq::SliceRandom;
+use rand::seq::index;
 use csv;
 use FeedForward;
+use Transform;
 
 /// Trait for getting specific element from set.
 ///
@@ -38,6 +45,11 @@ pub trait Extractable {
     ///
     /// * `return` - length of set.
     fn len(&self) -> usize;
+
+    /// Check whether set has no elements
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// Container for data storage. It is not important to use it but it can significantly
@@ -62,7 +74,7 @@ pub trait Extractable {
 ///
 /// /* etc */
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DataSet{
     x: Vec<Vec<f64>>,
     y: Vec<Vec<f64>>,
@@ -82,7 +94,7 @@ impl DataSet {
     /// let mut data = DataSet::new();
     /// ```
     pub fn new() -> DataSet{
-        return DataSet {
+        DataSet {
             x: vec![],
             y: vec![],
 
@@ -102,7 +114,7 @@ impl DataSet {
     ///
     /// * `file_path: &str` - path to `csv` file;
     /// * `return -> Result<DataSet, Box<std::error::Error>>` - return new `DataSet`
-    /// instance if Ok.
+    ///   instance if Ok.
     ///
     /// # Examples
     ///
@@ -166,6 +178,7 @@ impl DataSet {
     /// Expected output
     ///
     /// `[2.4] [2.2, 2.1]`
+    #[allow(clippy::needless_range_loop)]
     pub fn sum(&self) -> (Vec<f64>, Vec<f64>){
         let mut sum_x = vec![0.0; self.x[0].len()];
         let mut sum_y = vec![0.0; self.y[0].len()];
@@ -207,13 +220,13 @@ impl DataSet {
         let (sum_x, sum_y) = self.sum();
         let mut mean_x = sum_x.clone().to_vec();
 
-        for i in 0..self.x[0].len(){
-            mean_x[i] /= self.x.len() as f64;
+        for v in mean_x.iter_mut(){
+            *v /= self.x.len() as f64;
         }
 
         let mut mean_y = sum_y.clone().to_vec();
-        for i in 0..self.y[0].len(){
-            mean_y[i] /= self.y.len() as f64;
+        for v in mean_y.iter_mut(){
+            *v /= self.y.len() as f64;
         }
 
         (mean_x, mean_y)
@@ -282,15 +295,49 @@ impl DataSet {
         self.tx = vec![];
         self.ty = vec![];
 
-        let amount = (self.x.len() as f64 * proportion) as i32;
-        for _ in 0..amount{
-            let i = self.rand_index();
+        let amount = (self.x.len() as f64 * proportion) as usize;
+        let mut is_test = vec![false; self.x.len()];
+        for i in index::sample(&mut thread_rng(), self.x.len(), amount).into_iter(){
+            is_test[i] = true;
+        }
 
-            self.tx.push(self.x[i].clone());
-            self.ty.push(self.y[i].clone());
+        let x = std::mem::take(&mut self.x);
+        let y = std::mem::take(&mut self.y);
+        for ((x, y), t) in x.into_iter().zip(y).zip(is_test){
+            if t {
+                self.tx.push(x);
+                self.ty.push(y);
+            } else {
+                self.x.push(x);
+                self.y.push(y);
+            }
+        }
+    }
 
-            self.remove(i);
+    /// Get test set separated by `divide` method as a new `DataSet`.
+    ///
+    /// * `return -> DataSet` - copy of test set, it is empty if `divide` was not called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use neuroflow::data::{DataSet, Extractable};
+    ///
+    /// let mut data = DataSet::new();
+    /// for i in 0..10 {
+    ///     data.push(&[i as f64], &[0.0]);
+    /// }
+    /// data.divide(0.2);
+    ///
+    /// assert_eq!(data.test_set().len(), 2);
+    /// assert_eq!(data.len(), 8);
+    /// ```
+    pub fn test_set(&self) -> DataSet{
+        let mut test = DataSet::new();
+        for i in 0..self.tx.len(){
+            test.push(&self.tx[i], &self.ty[i]);
         }
+        test
     }
 
     /// Remove element by index from set
@@ -311,11 +358,6 @@ impl DataSet {
         self.y.remove(i);
     }
 
-    fn rand_index(&self) -> usize {
-        let mut rng = thread_rng();
-        rng.sample(Uniform::new(0, self.y.len()))
-    }
-
     /// Don't use this method. It is only for me and will be deleted
     /// as soon as possible
     pub fn cv(&self, nn: &mut FeedForward) -> f64 {
@@ -330,12 +372,16 @@ impl DataSet {
         }
 
         let len = self.ty.len() as f64;
-
-        let _ = error.iter().map(|x| x / len).collect::<Vec<f64>>();
         error.iter().sum::<f64>() / len
     }
 }
 
+impl Default for DataSet{
+    fn default() -> DataSet {
+        DataSet::new()
+    }
+}
+
 impl Extractable for DataSet{
     fn rand(&self) -> (&Vec<f64>, &Vec<f64>){
         let mut rnd_range = thread_rng();
@@ -349,4 +395,582 @@ impl Extractable for DataSet{
     fn len(&self) -> usize {
         self.y.len()
     }
-}
\ No newline at end of file
+}
+
+/// Splitter of data into training, validation and test sets.
+///
+/// Elements are assigned to sets in linear time. Split may be reproduced by
+/// setting the seed of random numbers generator. Stratified split keeps
+/// proportions of classes in each set, group-aware split keeps all elements
+/// of a group in the same set.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::data::{DataSet, Extractable, Splitter};
+///
+/// let mut data = DataSet::new();
+/// for i in 0..100 {
+///     data.push(&[i as f64], &[(i % 2) as f64]);
+/// }
+///
+/// let (train, validation, test) = Splitter::new(0.2, 0.1)
+///     .seed(42)
+///     .stratify(true)
+///     .split(&data);
+///
+/// assert_eq!((train.len(), validation.len(), test.len()), (70, 20, 10));
+/// ```
+pub struct Splitter {
+    validation: f64,
+    test: f64,
+    seed: Option<u64>,
+    stratify: bool,
+    groups: Vec<usize>,
+}
+
+impl Splitter {
+    /// `Splitter` constructor.
+    ///
+    /// * `validation: f64` - share of elements in validation set;
+    /// * `test: f64` - share of elements in test set. The rest elements are in training set.
+    pub fn new(validation: f64, test: f64) -> Splitter {
+        assert!(validation >= 0.0 && test >= 0.0 && validation + test <= 1.0,
+                "shares of validation and test sets must be non-negative and not exceed 1 in total");
+        Splitter {validation, test, seed: None, stratify: false, groups: Vec::new()}
+    }
+
+    /// Set seed of random numbers generator, so split is reproducible.
+    pub fn seed(&mut self, seed: u64) -> &mut Splitter {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Keep proportions of classes in each set. Class of element is the index
+    /// of maximal element of its expected output vector (one-hot encoding) or
+    /// the rounded value if expected output has single element.
+    pub fn stratify(&mut self, stratify: bool) -> &mut Splitter {
+        self.stratify = stratify;
+        self
+    }
+
+    /// Set group of each element of data. Elements of the same group are always
+    /// put in the same set, so shares of sets are kept approximately.
+    /// It cannot be combined with stratification.
+    ///
+    /// * `groups: &[usize]` - group of each element of data.
+    pub fn groups(&mut self, groups: &[usize]) -> &mut Splitter {
+        self.groups = groups.to_vec();
+        self
+    }
+
+    /// Split data into training, validation and test sets. Order of elements
+    /// in each set is the same as in data.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `return -> (DataSet, DataSet, DataSet)` - training, validation and test sets.
+    pub fn split<T>(&self, data: &T) -> (DataSet, DataSet, DataSet) where T: Extractable{
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        // units are groups of elements that go to the same set
+        let units: Vec<Vec<usize>> = if !self.groups.is_empty() {
+            assert!(!self.stratify, "stratified split cannot be group-aware");
+            assert_eq!(self.groups.len(), data.len(), "each element of data must have its group");
+            collect(self.groups.iter().cloned())
+        } else if self.stratify {
+            collect((0..data.len()).map(|i| label(data.get(i).1)))
+        } else {
+            vec![(0..data.len()).collect()]
+        };
+
+        // 0 - training set, 1 - validation set, 2 - test set
+        let mut part = vec![0u8; data.len()];
+        if self.groups.is_empty() {
+            for mut unit in units.into_iter(){
+                unit.shuffle(&mut rng);
+
+                let test = (unit.len() as f64 * self.test).round() as usize;
+                let validation = ((unit.len() as f64 * self.validation).round() as usize).min(unit.len() - test);
+                for (n, i) in unit.into_iter().enumerate(){
+                    part[i] = if n < test {2} else if n < test + validation {1} else {0};
+                }
+            }
+        } else {
+            let mut units = units;
+            units.shuffle(&mut rng);
+
+            let test = (data.len() as f64 * self.test).round() as usize;
+            let validation = (data.len() as f64 * self.validation).round() as usize;
+            let mut count = 0;
+            for unit in units.into_iter(){
+                let p = if count < test {2} else if count < test + validation {1} else {0};
+                count += unit.len();
+                for i in unit.into_iter(){
+                    part[i] = p;
+                }
+            }
+        }
+
+        let mut sets = (DataSet::new(), DataSet::new(), DataSet::new());
+        for (i, p) in part.into_iter().enumerate(){
+            let (x, y) = data.get(i);
+            match p {
+                0 => sets.0.push(x, y),
+                1 => sets.1.push(x, y),
+                _ => sets.2.push(x, y),
+            }
+        }
+        sets
+    }
+}
+
+/// Class label of expected output vector
+fn label(y: &[f64]) -> i64 {
+    if y.len() == 1 {
+        return y[0].round() as i64;
+    }
+
+    let mut best = 0;
+    for i in 1..y.len(){
+        if y[i] > y[best] {
+            best = i;
+        }
+    }
+    best as i64
+}
+
+/// Collect indices of elements with equal keys in order of the first appearance of key
+fn collect<I, K>(keys: I) -> Vec<Vec<usize>> where I: Iterator<Item = K>, K: Hash + Eq{
+    let mut position: HashMap<K, usize> = HashMap::new();
+    let mut res: Vec<Vec<usize>> = Vec::new();
+
+    for (i, key) in keys.enumerate(){
+        let p = *position.entry(key).or_insert(res.len());
+        if p == res.len() {
+            res.push(Vec::new());
+        }
+        res[p].push(i);
+    }
+    res
+}
+
+/// Method of feature scaling.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Scaling {
+    /// Zero mean and unit standard deviation
+    Standard,
+    /// Range `[0, 1]`
+    MinMax,
+    /// Zero median and unit interquartile range, it is robust to outliers
+    Robust,
+    /// Range `[-1, 1]` by division by the maximal absolute value, zeros are kept
+    MaxAbs,
+}
+
+/// Scaler of vectors fitted to their components. Each component is scaled
+/// as `(x - shift) / scale`, where shift and scale depend on `Scaling` method.
+/// Components with zero spread are only shifted.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::data::{Scaler, Scaling};
+///
+/// let scaler = Scaler::fit(Scaling::MinMax, &[vec![1.0, -2.0], vec![3.0, 2.0]]);
+/// assert_eq!(scaler.transform(&[2.0, 0.0]), vec![0.5, 0.5]);
+/// assert_eq!(scaler.inverse_transform(&[1.0, 0.0]), vec![3.0, -2.0]);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Scaler {
+    method: Scaling,
+    shift: Vec<f64>,
+    scale: Vec<f64>,
+}
+
+/// Quantile of sorted values with linear interpolation
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (low, high) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[low] + (sorted[high] - sorted[low]) * (pos - low as f64)
+}
+
+impl Scaler {
+    /// Fit scaler to components of vectors.
+    ///
+    /// * `method: Scaling` - method of scaling;
+    /// * `vectors: &[Vec<f64>]` - vectors of equal length;
+    /// * `return -> Scaler` - fitted scaler.
+    ///
+    /// # Panics
+    ///
+    /// If there are no vectors.
+    pub fn fit(method: Scaling, vectors: &[Vec<f64>]) -> Scaler {
+        assert!(!vectors.is_empty(), "scaler must be fitted to at least one vector");
+
+        let n = vectors.len() as f64;
+        let mut shift = Vec::new();
+        let mut scale = Vec::new();
+
+        for k in 0..vectors[0].len(){
+            let column: Vec<f64> = vectors.iter().map(|v| v[k]).collect();
+
+            let (s, d) = match method {
+                Scaling::Standard => {
+                    let mean = column.iter().sum::<f64>() / n;
+                    let var = column.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+                    (mean, var.sqrt())
+                },
+                Scaling::MinMax => {
+                    let min = column.iter().cloned().fold(f64::INFINITY, f64::min);
+                    let max = column.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                    (min, max - min)
+                },
+                Scaling::Robust => {
+                    let mut sorted = column;
+                    sorted.sort_by(|a, b| a.total_cmp(b));
+                    (quantile(&sorted, 0.5), quantile(&sorted, 0.75) - quantile(&sorted, 0.25))
+                },
+                Scaling::MaxAbs => (0.0, column.iter().fold(0.0, |m, x| f64::max(m, x.abs()))),
+            };
+
+            shift.push(s);
+            scale.push(if d == 0.0 {1.0} else {d});
+        }
+
+        Scaler {method, shift, scale}
+    }
+
+    /// Get method of scaling.
+    pub fn method(&self) -> Scaling {
+        self.method
+    }
+
+    /// Scale vector.
+    ///
+    /// * `x: &[f64]` - vector of the same length as fitted ones;
+    /// * `return -> Vec<f64>` - scaled copy of vector.
+    ///
+    /// # Panics
+    ///
+    /// If length of vector differs from length of fitted ones.
+    pub fn transform(&self, x: &[f64]) -> Vec<f64> {
+        let mut res = x.to_vec();
+        self.transform_mut(&mut res);
+        res
+    }
+
+    /// Scale vector in place.
+    pub fn transform_mut(&self, x: &mut [f64]) {
+        self.check(x);
+        for (v, (s, d)) in x.iter_mut().zip(self.shift.iter().zip(self.scale.iter())){
+            *v = (*v - s) / d;
+        }
+    }
+
+    /// Restore vector from its scaled form, e.g. output of network
+    /// trained on scaled data.
+    ///
+    /// * `x: &[f64]` - scaled vector;
+    /// * `return -> Vec<f64>` - vector in the original units.
+    ///
+    /// # Panics
+    ///
+    /// If length of vector differs from length of fitted ones.
+    pub fn inverse_transform(&self, x: &[f64]) -> Vec<f64> {
+        let mut res = x.to_vec();
+        self.inverse_transform_mut(&mut res);
+        res
+    }
+
+    /// Restore vector from its scaled form in place.
+    pub fn inverse_transform_mut(&self, x: &mut [f64]) {
+        self.check(x);
+        for (v, (s, d)) in x.iter_mut().zip(self.shift.iter().zip(self.scale.iter())){
+            *v = *v * d + s;
+        }
+    }
+
+    /// Check that vector has the same length as fitted ones
+    fn check(&self, x: &[f64]) {
+        assert_eq!(x.len(), self.shift.len(), "scaler is fitted to vectors of length {}, but vector of length {} is given",
+                   self.shift.len(), x.len());
+    }
+}
+
+impl Transform for Scaler {}
+
+/// Scalers of input vectors and desired outputs of data set. Scalers are
+/// fitted to training elements only, but both training and test sets
+/// are transformed.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::data::{DataSet, DataScaler, Scaling};
+/// use neuroflow::io;
+///
+/// let mut data = DataSet::new();
+/// for i in 0..50 {
+///     let x = i as f64 * 10.0;
+///     data.push(&[x], &[x.sqrt() * 100.0]);
+/// }
+///
+/// let scaler = DataScaler::fit(&data, Scaling::Standard, Some(Scaling::MinMax));
+/// scaler.transform_mut(&mut data);
+///
+/// let mut nn = FeedForward::new(&[1, 4, 1]);
+/// nn.train(&data, 1_000);
+///
+/// let y = nn.calc(&scaler.transform_input(&[250.0])).to_vec();
+/// let prediction = scaler.inverse_transform(&y);
+///
+/// io::save(&mut scaler.clone(), "scaler.flow").unwrap();
+/// let restored: DataScaler = io::load("scaler.flow").unwrap();
+/// # std::fs::remove_file("scaler.flow").unwrap();
+/// assert_eq!(restored, scaler);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DataScaler {
+    x: Scaler,
+    y: Option<Scaler>,
+}
+
+impl DataScaler {
+    /// Fit scalers to training elements of data.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `inputs: Scaling` - method of scaling of input vectors;
+    /// * `outputs: Option<Scaling>` - method of scaling of desired outputs
+    ///   or `None` if outputs are kept as they are;
+    /// * `return -> DataScaler` - fitted scalers.
+    pub fn fit<T>(data: &T, inputs: Scaling, outputs: Option<Scaling>) -> DataScaler where T: Extractable{
+        let mut x = Vec::with_capacity(data.len());
+        let mut y = Vec::with_capacity(data.len());
+        for i in 0..data.len(){
+            let (a, b) = data.get(i);
+            x.push(a.clone());
+            y.push(b.clone());
+        }
+
+        DataScaler {x: Scaler::fit(inputs, &x), y: outputs.map(|m| Scaler::fit(m, &y))}
+    }
+
+    /// Get scaler of input vectors.
+    pub fn inputs(&self) -> &Scaler {
+        &self.x
+    }
+
+    /// Get scaler of desired outputs if they are scaled.
+    pub fn outputs(&self) -> Option<&Scaler> {
+        self.y.as_ref()
+    }
+
+    /// Scale training and test sets of data in place.
+    pub fn transform_mut(&self, data: &mut DataSet) {
+        for x in data.x.iter_mut().chain(data.tx.iter_mut()){
+            self.x.transform_mut(x);
+        }
+        if let Some(ref scaler) = self.y {
+            for y in data.y.iter_mut().chain(data.ty.iter_mut()){
+                scaler.transform_mut(y);
+            }
+        }
+    }
+
+    /// Scale copy of data.
+    ///
+    /// * `data: &DataSet` - data to be scaled;
+    /// * `return -> DataSet` - scaled copy of data.
+    pub fn transform(&self, data: &DataSet) -> DataSet {
+        let mut res = data.clone();
+        self.transform_mut(&mut res);
+        res
+    }
+
+    /// Scale input vector, e.g. before passing it to network.
+    pub fn transform_input(&self, x: &[f64]) -> Vec<f64> {
+        self.x.transform(x)
+    }
+
+    /// Restore output of network trained on scaled data to the original units.
+    /// Output is returned as is if desired outputs are not scaled.
+    pub fn inverse_transform(&self, y: &[f64]) -> Vec<f64> {
+        match self.y {
+            Some(ref scaler) => scaler.inverse_transform(y),
+            None => y.to_vec()
+        }
+    }
+}
+
+impl Transform for DataScaler {}
+
+/// Trait for getting specific sequence from set of sequences.
+/// It is the same as `Extractable` but each element of set is
+/// a sequence of vectors.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::data::SequenceExtractable;
+/// use neuroflow::data::SequenceSet;
+///
+/// let mut data = SequenceSet::new();
+/// data.push(&[vec![0.1], vec![0.2]], &[vec![0.3]]);
+/// data.rand();
+/// ```
+pub trait SequenceExtractable {
+    /// Get random sequence from set
+    ///
+    /// * `return` - tuple of two links on sequences of vectors.
+    fn rand(&self) -> (&Vec<Vec<f64>>, &Vec<Vec<f64>>);
+
+    /// Get sequence from set by index
+    ///
+    /// * `i: usize` - index of sequence;
+    /// * `return` - tuple of two links on sequences of vectors.
+    fn get(&self, i: usize) -> (&Vec<Vec<f64>>, &Vec<Vec<f64>>);
+
+    /// Get length of set
+    ///
+    /// * `return` - length of set.
+    fn len(&self) -> usize;
+
+    /// Check whether set has no elements
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Container for sequences. It is analogous to `DataSet` but stores the sequences
+/// of input vectors and sequences of desired output vectors for recurrent
+/// neural networks.
+///
+/// Desired output sequence may be shorter than input sequence. In this case
+/// it is aligned to the end of input sequence, e.g. single output vector
+/// is the desired output for the last step of input sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::data::SequenceSet;
+///
+/// let mut data = SequenceSet::new();
+///
+/// /* Desired output for each step */
+/// data.push(&[vec![0.1], vec![0.2], vec![0.3]], &[vec![0.2], vec![0.3], vec![0.4]]);
+///
+/// /* Desired output only for the last step */
+/// data.push(&[vec![0.1], vec![0.2], vec![0.3]], &[vec![0.4]]);
+/// ```
+#[derive(Debug)]
+pub struct SequenceSet{
+    x: Vec<Vec<Vec<f64>>>,
+    y: Vec<Vec<Vec<f64>>>,
+}
+
+impl SequenceSet {
+    /// `SequenceSet` constructor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use neuroflow::data::SequenceSet;
+    ///
+    /// let mut data = SequenceSet::new();
+    /// ```
+    pub fn new() -> SequenceSet{
+        SequenceSet {
+            x: vec![],
+            y: vec![],
+        }
+    }
+
+    /// Build `SequenceSet` from time series by sliding window.
+    /// Each sequence contains `window` consecutive values of series and the
+    /// desired output is the vector of `horizon` values that follow them.
+    ///
+    /// * `series: &[f64]` - time series;
+    /// * `window: usize` - length of input sequence;
+    /// * `horizon: usize` - amount of values to be forecasted;
+    /// * `return -> SequenceSet` - new `SequenceSet` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use neuroflow::data::{SequenceSet, SequenceExtractable};
+    ///
+    /// let data = SequenceSet::from_series(&[1.0, 2.0, 3.0, 4.0, 5.0], 3, 1);
+    ///
+    /// assert_eq!(data.len(), 2);
+    /// assert_eq!(data.get(1).1[0], vec![5.0]);
+    /// ```
+    pub fn from_series(series: &[f64], window: usize, horizon: usize) -> SequenceSet{
+        let mut data = SequenceSet::new();
+
+        if series.len() < window + horizon {
+            return data;
+        }
+
+        for i in 0..series.len() - window - horizon + 1{
+            let x: Vec<Vec<f64>> = series[i..i + window].iter().map(|v| vec![*v]).collect();
+            let y = series[i + window..i + window + horizon].to_vec();
+            data.push(&x, &[y]);
+        }
+
+        data
+    }
+
+    /// Append sequence to the end of the set.
+    ///
+    /// * `x: &[Vec<f64>]` - input sequence;
+    /// * `y: &[Vec<f64>]` - expected output sequence. It must not be longer
+    ///   than input sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use neuroflow::data::SequenceSet;
+    ///
+    /// let mut data = SequenceSet::new();
+    /// data.push(&[vec![1.3], vec![1.2]], &[vec![2.1]]);
+    /// ```
+    pub fn push(&mut self, x: &[Vec<f64>], y: &[Vec<f64>]){
+        assert!(y.len() <= x.len(), "expected output sequence is longer than input sequence");
+
+        self.x.push(x.to_vec());
+        self.y.push(y.to_vec());
+    }
+
+    /// Remove sequence by index from set
+    ///
+    /// * `i: usize` - index of sequence to be deleted.
+    pub fn remove(&mut self, i: usize){
+        self.x.remove(i);
+        self.y.remove(i);
+    }
+}
+
+impl Default for SequenceSet{
+    fn default() -> SequenceSet {
+        SequenceSet::new()
+    }
+}
+
+impl SequenceExtractable for SequenceSet{
+    fn rand(&self) -> (&Vec<Vec<f64>>, &Vec<Vec<f64>>){
+        let mut rnd_range = thread_rng();
+        let k = rnd_range.sample(Uniform::new(0, self.y.len()));
+
+        (&self.x[k], &self.y[k])
+    }
+    fn get(&self, i: usize) -> (&Vec<Vec<f64>>, &Vec<Vec<f64>>){
+        (&self.x[i], &self.y[i])
+    }
+    fn len(&self) -> usize {
+        self.y.len()
+    }
+}
@@ -0,0 +1,256 @@
+//! Module contains embedding layer that maps categorical (integer) inputs
+//! to trainable dense vectors.
+//!
+//! Instead of one-hot encoding of high-cardinality categories each category
+//! is represented by a row of `Embedding` matrix. The vectors of all categorical
+//! inputs are concatenated with numeric features and passed to `FeedForward`
+//! network. Only the rows that were used by a sample are updated while training.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::embedding::EmbeddingNetwork;
+//! use neuroflow::data::DataSet;
+//!
+//! /*
+//!     Two categorical inputs: the first one has 1000 categories mapped into
+//!     vectors of length 4, the second has 20 categories mapped into vectors of length 2.
+//!     Then there are 3 numeric features, one hidden layer and single output.
+//! */
+//! let mut nn = EmbeddingNetwork::new(&[(1000, 4), (20, 2)], &[3, 8, 1]);
+//!
+//! /* IDs are placed in the first columns of input vector */
+//! let mut data = DataSet::new();
+//! data.push(&[512.0, 3.0, 0.1, 0.2, 0.3], &[1.0]);
+//! data.push(&[17.0, 19.0, 0.3, 0.2, 0.1], &[0.0]);
+//!
+//! nn.learning_rate(0.01)
+//!     .train(&data, 1_000);
+//!
+//! let res = nn.calc(&[512, 3], &[0.1, 0.2, 0.3])[0];
+//! ```
+
+use math;
+use activators;
+use data::Extractable;
+
+use FeedForward;
+use Transform;
+use ErrorKind;
+
+/// Matrix of trainable vectors. Each row is the vector of one category.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::embedding::Embedding;
+///
+/// let e = Embedding::new(100, 8);
+/// assert_eq!(e.vector(42).len(), 8);
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct Embedding {
+    w: Vec<Vec<f64>>,
+}
+
+impl Embedding {
+    /// The constructor of `Embedding` struct. Vectors are initialized with
+    /// random values.
+    ///
+    /// * `vocabulary: usize` - amount of categories;
+    /// * `dim: usize` - length of vector of each category;
+    /// * `return` - `Embedding` struct.
+    pub fn new(vocabulary: usize, dim: usize) -> Embedding {
+        Embedding {w: math::random(vocabulary, dim, 1.0 / (dim as f64).sqrt())}
+    }
+
+    /// Get vector of category.
+    ///
+    /// * `id: usize` - category;
+    /// * `return -> &[f64]` - vector of category.
+    pub fn vector(&self, id: usize) -> &[f64] {
+        assert!(id < self.w.len(), "category {} is out of vocabulary of size {}", id, self.w.len());
+        &self.w[id]
+    }
+
+    /// Get amount of categories
+    pub fn vocabulary(&self) -> usize {
+        self.w.len()
+    }
+
+    /// Get length of vectors
+    pub fn dim(&self) -> usize {
+        if self.w.is_empty() { 0 } else { self.w[0].len() }
+    }
+
+    /// Move vector of category along the gradient
+    #[allow(clippy::needless_range_loop)]
+    fn update(&mut self, id: usize, delta: &[f64], learning_rate: f64){
+        for j in 0..delta.len(){
+            self.w[id][j] += learning_rate * delta[j];
+        }
+    }
+}
+
+/// `FeedForward` network with embedding layers for categorical inputs.
+/// Vectors of all categorical inputs are concatenated with numeric features
+/// and then passed to the dense layers.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::embedding::EmbeddingNetwork;
+///
+/// // Single categorical input with 50 categories mapped to vectors of length 3,
+/// // 2 numeric features and 1 output
+/// let mut nn = EmbeddingNetwork::new(&[(50, 3)], &[2, 4, 1]);
+///
+/// nn.fit(&[7], &[0.5, 0.1], &[1.0]);
+/// let res = nn.calc(&[7], &[0.5, 0.1])[0];
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct EmbeddingNetwork {
+    embeddings: Vec<Embedding>,
+    nn: FeedForward,
+}
+
+impl EmbeddingNetwork {
+    /// The constructor of `EmbeddingNetwork` struct
+    ///
+    /// * `embeddings: &[(usize, usize)]` - pairs of vocabulary size and vector length
+    ///   for each categorical input;
+    /// * `architecture: &[i32]` - the architecture of dense layers where the first
+    ///   element is amount of numeric features (it may be `0`) and the last one is
+    ///   amount of neurons in output layer;
+    /// * `return` - `EmbeddingNetwork` struct.
+    pub fn new(embeddings: &[(usize, usize)], architecture: &[i32]) -> EmbeddingNetwork {
+        let mut arch = architecture.to_vec();
+        for &(_, dim) in embeddings{
+            arch[0] += dim as i32;
+        }
+
+        EmbeddingNetwork {
+            embeddings: embeddings.iter().map(|&(v, d)| Embedding::new(v, d)).collect(),
+            nn: FeedForward::new(&arch),
+        }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn input(&self, ids: &[usize], x: &[f64]) -> Vec<f64> {
+        assert_eq!(ids.len(), self.embeddings.len(), "amount of IDs must be equal to amount of embeddings");
+
+        let mut input = vec![1f64];
+        for i in 0..ids.len(){
+            input.extend_from_slice(self.embeddings[i].vector(ids[i]));
+        }
+        input.extend_from_slice(x);
+        input
+    }
+
+    /// Train network by bulked data. The first columns of input vectors
+    /// contain IDs of categories (one for each embedding) and the rest ones
+    /// contain numeric features.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `iterations: i64` - iterations count.
+    ///
+    /// # Panics
+    ///
+    /// If ID of category is not a non-negative integer less than vocabulary size.
+    pub fn train<T>(&mut self, data: &T, iterations: i64) where T: Extractable{
+        let k = self.embeddings.len();
+        for _ in 0..iterations{
+            let (x, y) = data.rand();
+            let ids: Vec<usize> = x[..k].iter().enumerate().map(|(i, v)| {
+                let vocabulary = self.embeddings[i].vocabulary();
+                assert!(*v >= 0.0 && v.fract() == 0.0 && *v < vocabulary as f64,
+                        "category {} of input {} is not an ID in vocabulary of size {}", v, i, vocabulary);
+                *v as usize
+            }).collect();
+            self.fit(&ids, &x[k..], y);
+        }
+    }
+
+    /// Train network simultaneously step by step. Only vectors of categories
+    /// given in `ids` are updated.
+    ///
+    /// * `ids: &[usize]` - categories of each categorical input;
+    /// * `x: &[f64]` - numeric features;
+    /// * `d: &[f64]` - expected output.
+    #[allow(clippy::needless_range_loop)]
+    pub fn fit(&mut self, ids: &[usize], x: &[f64], d: &[f64]){
+        let input = self.input(ids, x);
+
+        self.nn.forward(&input);
+        self.nn.backward(d);
+        let delta = self.nn.input_delta();
+        self.nn.update(&input);
+
+        let mut offset = 0;
+        for i in 0..ids.len(){
+            let dim = self.embeddings[i].dim();
+            self.embeddings[i].update(ids[i], &delta[offset..offset + dim], self.nn.learn_rate);
+            offset += dim;
+        }
+    }
+
+    /// Calculate the response by trained network.
+    ///
+    /// * `ids: &[usize]` - categories of each categorical input;
+    /// * `x: &[f64]` - numeric features;
+    /// * `return -> &[f64]` - slice of calculated data.
+    pub fn calc(&mut self, ids: &[usize], x: &[f64]) -> &[f64]{
+        let input = self.input(ids, x);
+        self.nn.forward(&input);
+        &self.nn.layers[self.nn.layers.len() - 1].y
+    }
+
+    /// Get embedding of categorical input.
+    ///
+    /// * `i: usize` - index of categorical input;
+    /// * `return -> &Embedding` - link on embedding.
+    pub fn embedding(&self, i: usize) -> &Embedding {
+        &self.embeddings[i]
+    }
+
+    /// Choose activation function of dense layers.
+    ///
+    /// * `func: neuroflow::activators::Type` - enum element that indicates which
+    ///   function to use;
+    /// * `return -> &mut EmbeddingNetwork` - link on the current struct.
+    pub fn activation(&mut self, func: activators::Type) -> &mut EmbeddingNetwork {
+        self.nn.activation(func);
+        self
+    }
+
+    /// Set the learning rate of network. The same rate is used for embeddings.
+    ///
+    /// * `learning_rate: f64` - learning rate;
+    /// * `return -> &mut EmbeddingNetwork` - link on the current struct.
+    pub fn learning_rate(&mut self, learning_rate: f64) -> &mut EmbeddingNetwork {
+        self.nn.learning_rate(learning_rate);
+        self
+    }
+
+    /// Set the momentum of dense layers.
+    ///
+    /// * `momentum: f64` - momentum;
+    /// * `return -> &mut EmbeddingNetwork` - link on the current struct.
+    pub fn momentum(&mut self, momentum: f64) -> &mut EmbeddingNetwork {
+        self.nn.momentum(momentum);
+        self
+    }
+
+    /// Get current training error
+    ///
+    /// * `return -> f64` - training error
+    pub fn get_error(&self) -> f64 {
+        self.nn.get_error()
+    }
+}
+
+impl Transform for EmbeddingNetwork{
+    fn after(&mut self) -> Result<(), ErrorKind>{
+        self.nn.after()
+    }
+}
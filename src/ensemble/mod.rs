@@ -0,0 +1,277 @@
+//! Module contains ensembles of `FeedForward` neural networks.
+//!
+//! Each member of ensemble is trained on its own bootstrap sample of data
+//! (bagging). Ensemble predicts by averaging outputs of members for regression
+//! or by voting for classification. Disagreement between members can be used
+//! as a measure of uncertainty of prediction.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::ensemble::Ensemble;
+//! use neuroflow::data::DataSet;
+//!
+//! let mut data = DataSet::new();
+//! data.push(&[0.0, 1.0], &[1.0]);
+//! data.push(&[1.0, 0.0], &[1.0]);
+//! data.push(&[1.0, 1.0], &[0.0]);
+//!
+//! /* Ensemble of 5 networks with architecture [2, 4, 1] */
+//! let mut ensemble = Ensemble::new(&[2, 4, 1], 5);
+//! ensemble.learning_rate(0.05)
+//!     .train(&data, 1_000);
+//!
+//! let res = ensemble.calc(&[0.0, 1.0])[0];
+//! let uncertainty = ensemble.disagreement(&[0.0, 1.0]);
+//! ```
+
+use rand::{thread_rng, Rng};
+use rand::distributions::Uniform;
+
+use data::Extractable;
+use activators;
+
+use FeedForward;
+use Transform;
+use ErrorKind;
+
+/// Way of combining outputs of members of ensemble.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Aggregation {
+    /// Mean of outputs of members. It is used for regression
+    Average,
+    /// Share of members which vote for each class, i.e. whose output
+    /// is maximal at this class. It is used for classification
+    Vote,
+}
+
+/// Bootstrap sample of data, i.e. random indices of elements drawn with replacement
+struct Bootstrap<'a, T: 'a> {
+    data: &'a T,
+    indices: Vec<usize>,
+}
+
+impl<'a, T> Bootstrap<'a, T> where T: Extractable {
+    fn new(data: &'a T) -> Bootstrap<'a, T> {
+        assert!(data.len() > 0, "bootstrap sample cannot be drawn from empty data");
+
+        let mut rng = thread_rng();
+        let range = Uniform::new(0, data.len());
+        let indices = (0..data.len()).map(|_| rng.sample(range)).collect();
+
+        Bootstrap {data, indices}
+    }
+}
+
+impl<'a, T> Extractable for Bootstrap<'a, T> where T: Extractable {
+    fn rand(&self) -> (&Vec<f64>, &Vec<f64>){
+        let k = thread_rng().sample(Uniform::new(0, self.indices.len()));
+        self.data.get(self.indices[k])
+    }
+    fn get(&self, i: usize) -> (&Vec<f64>, &Vec<f64>){
+        self.data.get(self.indices[i])
+    }
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+/// Index of maximal element
+fn argmax(v: &[f64]) -> usize {
+    let mut best = 0;
+    for i in 1..v.len(){
+        if v[i] > v[best] {
+            best = i;
+        }
+    }
+    best
+}
+
+/// Ensemble of `FeedForward` neural networks.
+///
+/// # Examples
+///
+/// Ensemble is saved and loaded as a single unit:
+///
+/// ```rust
+/// use neuroflow::ensemble::{Ensemble, Aggregation};
+/// use neuroflow::io;
+///
+/// let mut ensemble = Ensemble::new(&[2, 3, 2], 3);
+/// ensemble.aggregation(Aggregation::Vote);
+///
+/// io::save(&mut ensemble, "ensemble.flow").unwrap();
+/// let mut restored: Ensemble = io::load("ensemble.flow").unwrap();
+/// # std::fs::remove_file("ensemble.flow").unwrap();
+/// assert_eq!(restored.len(), 3);
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct Ensemble {
+    members: Vec<FeedForward>,
+    aggregation: Aggregation,
+
+    #[serde(skip_deserializing, skip_serializing)]
+    y: Vec<f64>,
+}
+
+impl Ensemble {
+    /// The constructor of `Ensemble` struct.
+    ///
+    /// * `architecture: &[i32]` - the architecture of each member in the same form
+    ///   as for `FeedForward::new`;
+    /// * `size: usize` - amount of members;
+    /// * `return` - `Ensemble` struct averaging outputs of members.
+    pub fn new(architecture: &[i32], size: usize) -> Ensemble {
+        let members = (0..size).map(|_| FeedForward::new(architecture)).collect();
+        Ensemble::with_members(members)
+    }
+
+    /// Create ensemble from already created or trained networks.
+    ///
+    /// * `members: Vec<FeedForward>` - networks with the same amount of inputs and outputs;
+    /// * `return` - `Ensemble` struct averaging outputs of members.
+    pub fn with_members(members: Vec<FeedForward>) -> Ensemble {
+        assert!(!members.is_empty(), "ensemble must have at least one member");
+        Ensemble {members, aggregation: Aggregation::Average, y: Vec::new()}
+    }
+
+    /// Choose way of combining outputs of members.
+    pub fn aggregation(&mut self, aggregation: Aggregation) -> &mut Ensemble {
+        self.aggregation = aggregation;
+        self
+    }
+
+    /// Set activation function of all members.
+    pub fn activation(&mut self, func: activators::Type) -> &mut Ensemble {
+        for nn in self.members.iter_mut(){
+            nn.activation(func.clone());
+        }
+        self
+    }
+
+    /// Set learning rate of all members.
+    pub fn learning_rate(&mut self, learning_rate: f64) -> &mut Ensemble {
+        for nn in self.members.iter_mut(){
+            nn.learning_rate(learning_rate);
+        }
+        self
+    }
+
+    /// Set momentum of all members.
+    pub fn momentum(&mut self, momentum: f64) -> &mut Ensemble {
+        for nn in self.members.iter_mut(){
+            nn.momentum(momentum);
+        }
+        self
+    }
+
+    /// Train each member on its own bootstrap sample of data.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `iterations: i64` - iterations count for each member.
+    ///
+    /// # Panics
+    ///
+    /// If data is empty.
+    pub fn train<T>(&mut self, data: &T, iterations: i64) where T: Extractable{
+        for nn in self.members.iter_mut(){
+            let sample = Bootstrap::new(data);
+            nn.train(&sample, iterations);
+        }
+    }
+
+    /// Calculate outputs of all members.
+    ///
+    /// * `X: &[f64]` - slice of input data;
+    /// * `return -> Vec<Vec<f64>>` - output of each member.
+    #[allow(non_snake_case)]
+    pub fn outputs(&mut self, X: &[f64]) -> Vec<Vec<f64>>{
+        self.members.iter_mut().map(|nn| nn.calc(X).to_vec()).collect()
+    }
+
+    /// Calculate the response of ensemble. In case of `Aggregation::Average` it is
+    /// mean of outputs of members, in case of `Aggregation::Vote` it is share
+    /// of votes for each class.
+    ///
+    /// * `X: &[f64]` - slice of input data;
+    /// * `return -> &[f64]` - output of ensemble.
+    #[allow(non_snake_case)]
+    pub fn calc(&mut self, X: &[f64]) -> &[f64]{
+        let outputs = self.outputs(X);
+        self.y = self.aggregate(&outputs);
+        &self.y
+    }
+
+    /// Combine outputs of members according to the way of aggregation
+    fn aggregate(&self, outputs: &[Vec<f64>]) -> Vec<f64> {
+        let n = outputs.len() as f64;
+        let mut y = vec![0.0; outputs[0].len()];
+
+        for out in outputs.iter(){
+            match self.aggregation {
+                Aggregation::Average => {
+                    for k in 0..y.len(){
+                        y[k] += out[k] / n;
+                    }
+                }
+                Aggregation::Vote => y[argmax(out)] += 1.0 / n,
+            }
+        }
+        y
+    }
+
+    /// Disagreement between members. In case of `Aggregation::Average` it is
+    /// mean over outputs of standard deviation of members' outputs, in case of
+    /// `Aggregation::Vote` it is share of members which do not vote for
+    /// the winning class.
+    ///
+    /// * `X: &[f64]` - slice of input data;
+    /// * `return -> f64` - disagreement, `0` if all members agree.
+    #[allow(non_snake_case)]
+    pub fn disagreement(&mut self, X: &[f64]) -> f64{
+        let outputs = self.outputs(X);
+        let y = self.aggregate(&outputs);
+
+        match self.aggregation {
+            Aggregation::Average => {
+                let n = outputs.len() as f64;
+                let mut sum = 0.0;
+                for k in 0..y.len(){
+                    let var: f64 = outputs.iter().map(|out| (out[k] - y[k]).powi(2)).sum::<f64>() / n;
+                    sum += var.sqrt();
+                }
+                sum / y.len() as f64
+            }
+            Aggregation::Vote => 1.0 - y[argmax(&y)],
+        }
+    }
+
+    /// Get member of ensemble.
+    ///
+    /// * `i: usize` - index of member;
+    /// * `return -> &FeedForward` - member network.
+    pub fn member(&self, i: usize) -> &FeedForward {
+        &self.members[i]
+    }
+
+    /// Get amount of members. Ensemble is never empty.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+}
+
+impl Transform for Ensemble{
+    fn before(&mut self){
+        for nn in self.members.iter_mut(){
+            nn.before();
+        }
+    }
+
+    fn after(&mut self) -> Result<(), ErrorKind>{
+        for nn in self.members.iter_mut(){
+            nn.after()?;
+        }
+        Ok(())
+    }
+}
@@ -17,3 +17,26 @@ pub fn widrows(architecture: &[i32], allowed_error: f64) -> f64 {
 
     (s as f64) / allowed_error
 }
+
+/// # Capacity of Hopfield network
+/// The amount of random patterns that can be stored in Hopfield network
+/// with Hebbian learning. If more patterns are stored the network
+/// fails to recall them. At this load about 1.5% of bits of
+/// recalled patterns may be wrong.
+/// ## Example
+/// For network of 100 neurons capacity is about 13.8 patterns
+pub fn hopfield_capacity(neurons: usize) -> f64 {
+    0.138 * neurons as f64
+}
+
+/// # Perfect capacity of Hopfield network
+/// The amount of random patterns that can be stored in Hopfield network
+/// with Hebbian learning so that all of them are recalled without errors
+/// with high probability. It is `N / (2 ln N)` for network of `N` neurons.
+pub fn hopfield_perfect_capacity(neurons: usize) -> f64 {
+    let n = neurons as f64;
+    if neurons < 2 {
+        return 0.0;
+    }
+    n / (2.0 * n.ln())
+}
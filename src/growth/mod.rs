@@ -0,0 +1,136 @@
+//! Module contains constructive training of `FeedForward` neural networks.
+//!
+//! Dynamic node creation starts from a small network and adds hidden neurons
+//! one by one. After each addition network is trained and evaluated on
+//! validation data. Growth stops when validation error stops improving and
+//! the best network found is kept.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::FeedForward;
+//! use neuroflow::data::DataSet;
+//! use neuroflow::growth::Growth;
+//!
+//! let mut train = DataSet::new();
+//! let mut validation = DataSet::new();
+//! for i in 0..40 {
+//!     let x = i as f64 / 20.0 - 1.0;
+//!     train.push(&[x], &[x.powi(2)]);
+//!     validation.push(&[x + 0.025], &[(x + 0.025).powi(2)]);
+//! }
+//!
+//! let mut nn = FeedForward::new(&[1, 1, 1]);
+//! let errors = Growth::new()
+//!     .iterations(2_000)
+//!     .max_neurons(5)
+//!     .run(&mut nn, &train, &validation);
+//! ```
+
+use data::Extractable;
+use metrics::mse;
+use FeedForward;
+
+/// Dynamic node creation algorithm.
+///
+/// New neurons are appended to the chosen hidden layer with random incoming
+/// and zero outgoing weights, so each addition does not spoil output of
+/// already trained network.
+pub struct Growth {
+    layer: usize,
+    iterations: i64,
+    max_neurons: usize,
+    patience: usize,
+    tolerance: f64,
+}
+
+impl Growth {
+    /// Create `Growth` with default parameters: it grows the first hidden
+    /// layer up to 32 neurons, trains 5000 iterations after each addition and
+    /// stops after the first addition which does not decrease validation error.
+    pub fn new() -> Growth {
+        Growth {layer: 1, iterations: 5_000, max_neurons: 32, patience: 1, tolerance: 0.0}
+    }
+
+    /// Choose hidden layer to grow.
+    ///
+    /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+    pub fn layer(&mut self, layer: usize) -> &mut Growth {
+        self.layer = layer;
+        self
+    }
+
+    /// Set amount of training iterations after each addition of neuron.
+    pub fn iterations(&mut self, iterations: i64) -> &mut Growth {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Set the maximal amount of neurons in the growing layer.
+    pub fn max_neurons(&mut self, max_neurons: usize) -> &mut Growth {
+        self.max_neurons = max_neurons;
+        self
+    }
+
+    /// Set amount of consecutive additions without improvement of validation
+    /// error after which growth stops.
+    pub fn patience(&mut self, patience: usize) -> &mut Growth {
+        self.patience = patience;
+        self
+    }
+
+    /// Set the minimal decrease of validation error that is considered
+    /// as improvement.
+    pub fn tolerance(&mut self, tolerance: f64) -> &mut Growth {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Grow and train network. When method returns, `nn` is the network
+    /// with the lowest validation error among all trained ones.
+    ///
+    /// * `nn: &mut FeedForward` - initial network;
+    /// * `train: &T` - training data that implements `neuroflow::data::Extractable` trait;
+    /// * `validation: &V` - validation data that implements `neuroflow::data::Extractable` trait;
+    /// * `return -> Vec<f64>` - mean squared validation error after each round,
+    ///   the first element is error of initial network.
+    pub fn run<T, V>(&self, nn: &mut FeedForward, train: &T, validation: &V) -> Vec<f64>
+        where T: Extractable, V: Extractable{
+        assert!(self.layer >= 1 && self.layer < nn.layers.len(), "only hidden layers can grow");
+
+        let mut errors = Vec::new();
+
+        nn.train(train, self.iterations);
+        errors.push(mse(nn, validation));
+
+        let mut best = nn.clone();
+        let mut best_error = errors[0];
+        let mut stale = 0;
+
+        while nn.layers[self.layer - 1].y.len() < self.max_neurons && stale < self.patience {
+            let neuron = nn.layers[self.layer - 1].y.len();
+            nn.bind(self.layer, neuron);
+            nn.train(train, self.iterations);
+
+            let error = mse(nn, validation);
+            errors.push(error);
+
+            if error < best_error - self.tolerance {
+                best = nn.clone();
+                best_error = error;
+                stale = 0;
+            } else {
+                stale += 1;
+            }
+        }
+
+        *nn = best;
+        errors
+    }
+}
+
+impl Default for Growth {
+    fn default() -> Growth {
+        Growth::new()
+    }
+}
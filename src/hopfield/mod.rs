@@ -0,0 +1,181 @@
+//! Module contains Hopfield network which works as associative memory.
+//!
+//! Binary patterns are stored in symmetric weights by Hebbian rule. When
+//! corrupted pattern is given, the network converges to the nearest stored
+//! pattern, decreasing its energy on each update.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::hopfield::{Hopfield, Update};
+//!
+//! let mut nn = Hopfield::new(6);
+//! nn.store(&[1.0, 1.0, 1.0, 0.0, 0.0, 0.0]);
+//! nn.store(&[1.0, 0.0, 1.0, 0.0, 1.0, 0.0]);
+//!
+//! /* The last bit of the first pattern is corrupted */
+//! let res = nn.recall(&[1.0, 1.0, 1.0, 0.0, 0.0, 1.0], Update::Asynchronous, 100);
+//! ```
+
+use rand::thread_rng;
+use rand::seq::SliceRandom;
+
+use estimators;
+use Transform;
+
+/// Determine the order of updates of neurons on recall.
+///
+/// * `Synchronous` - all neurons are updated at once;
+/// * `Asynchronous` - neurons are updated one by one in random order.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Update {
+    Synchronous,
+    Asynchronous
+}
+
+/// Hopfield network.
+///
+/// Patterns may be given as `0/1` or `-1/1` vectors: positive values are
+/// treated as `1` and other values as `-1`. States returned by network
+/// are bipolar, i.e. consist of `-1` and `1`.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::hopfield::{Hopfield, Update};
+///
+/// let mut nn = Hopfield::new(4);
+/// nn.store(&[1.0, -1.0, 1.0, -1.0]);
+///
+/// let res = nn.recall(&[1.0, 1.0, 1.0, -1.0], Update::Synchronous, 10);
+/// assert_eq!(res, vec![1.0, -1.0, 1.0, -1.0]);
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct Hopfield {
+    w: Vec<Vec<f64>>,
+    patterns: usize,
+}
+
+impl Hopfield {
+    /// The constructor of `Hopfield` struct
+    ///
+    /// * `neurons: usize` - amount of neurons, i.e. length of patterns;
+    /// * `return` - `Hopfield` struct.
+    pub fn new(neurons: usize) -> Hopfield {
+        Hopfield {w: vec![vec![0.0; neurons]; neurons], patterns: 0}
+    }
+
+    /// Store pattern in network by Hebbian rule.
+    ///
+    /// * `pattern: &[f64]` - binary pattern.
+    pub fn store(&mut self, pattern: &[f64]){
+        assert_eq!(pattern.len(), self.w.len(), "length of pattern must be equal to amount of neurons");
+
+        let s = bipolar(pattern);
+        let n = s.len() as f64;
+        for i in 0..s.len(){
+            for j in 0..s.len(){
+                if i != j {
+                    self.w[i][j] += s[i] * s[j] / n;
+                }
+            }
+        }
+        self.patterns += 1;
+    }
+
+    /// Recall stored pattern from the given one. Updates stop when state
+    /// does not change or after `max_iterations` sweeps through all neurons.
+    ///
+    /// * `pattern: &[f64]` - initial (possibly corrupted) binary pattern;
+    /// * `update: Update` - order of updates;
+    /// * `max_iterations: usize` - maximal amount of sweeps;
+    /// * `return -> Vec<f64>` - recalled bipolar pattern.
+    pub fn recall(&self, pattern: &[f64], update: Update, max_iterations: usize) -> Vec<f64> {
+        assert_eq!(pattern.len(), self.w.len(), "length of pattern must be equal to amount of neurons");
+
+        let mut s = bipolar(pattern);
+        let mut rng = thread_rng();
+        let mut order: Vec<usize> = (0..s.len()).collect();
+
+        for _ in 0..max_iterations{
+            let mut changed = false;
+            match update {
+                Update::Synchronous => {
+                    let next: Vec<f64> = (0..s.len()).map(|i| self.activate(&s, i)).collect();
+                    changed = next != s;
+                    s = next;
+                }
+                Update::Asynchronous => {
+                    order.shuffle(&mut rng);
+                    for &i in order.iter(){
+                        let v = self.activate(&s, i);
+                        if v != s[i] {
+                            s[i] = v;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        s
+    }
+
+    /// New state of neuron. Neuron keeps its state when its input is zero.
+    #[allow(clippy::needless_range_loop)]
+    fn activate(&self, s: &[f64], i: usize) -> f64 {
+        let mut h = 0.0;
+        for j in 0..s.len(){
+            h += self.w[i][j] * s[j];
+        }
+
+        if h > 0.0 {
+            1.0
+        } else if h < 0.0 {
+            -1.0
+        } else {
+            s[i]
+        }
+    }
+
+    /// Energy of network in the given state `E = -1/2 Σ w_ij s_i s_j`.
+    /// Stored patterns are local minima of energy.
+    ///
+    /// * `state: &[f64]` - binary state;
+    /// * `return -> f64` - energy.
+    pub fn energy(&self, state: &[f64]) -> f64 {
+        let s = bipolar(state);
+        let mut e = 0.0;
+        for i in 0..s.len(){
+            for j in 0..s.len(){
+                e -= 0.5 * self.w[i][j] * s[i] * s[j];
+            }
+        }
+        e
+    }
+
+    /// Estimated amount of patterns that network can store.
+    /// See `neuroflow::estimators::hopfield_capacity`.
+    pub fn capacity(&self) -> f64 {
+        estimators::hopfield_capacity(self.w.len())
+    }
+
+    /// Get amount of stored patterns
+    pub fn patterns(&self) -> usize {
+        self.patterns
+    }
+
+    /// Get amount of neurons
+    pub fn neurons(&self) -> usize {
+        self.w.len()
+    }
+}
+
+impl Transform for Hopfield{}
+
+/// Convert binary vector to bipolar one
+fn bipolar(pattern: &[f64]) -> Vec<f64> {
+    pattern.iter().map(|v| if *v > 0.0 { 1.0 } else { -1.0 }).collect()
+}
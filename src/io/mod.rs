@@ -62,9 +62,9 @@ pub fn save<T: Transform>(obj: &mut T, file_path: &str) -> Result<(), ErrorKind>
 
 /// Loads and restores the neural network from file.
 ///
-/// * `file_path: &'b str` - path to the file;
+/// * `file_path: &str` - path to the file;
 /// * `return -> Result<T, IOError>` - if Ok returns loaded neural network (Note, you must
-/// apparently specify the type T).
+///   apparently specify the type T).
 ///
 /// # Examples
 ///
@@ -75,12 +75,12 @@ pub fn save<T: Transform>(obj: &mut T, file_path: &str) -> Result<(), ErrorKind>
 /// let mut new_nn: FeedForward = io::load("test.flow")
 ///     .unwrap_or(FeedForward::new(&[2, 2, 1]));
 /// ```
-pub fn load<'b, T>(file_path: &'b str) -> Result<T, ErrorKind> where T: Transform{
+pub fn load<T>(file_path: &str) -> Result<T, ErrorKind> where T: Transform{
     let file = File::open(file_path).map_err(ErrorKind::IO)?;
     let mut buf = BufReader::new(file);
 
     let mut nn: T = deserialize_from(&mut buf).map_err(ErrorKind::Encoding)?;
-    nn.after();
+    nn.after()?;
     Ok(nn)
 }
 
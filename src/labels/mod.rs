@@ -0,0 +1,294 @@
+//! Module contains utilities for classification: encoder of class labels
+//! to one-hot vectors and classifier which bundles `FeedForward` network
+//! with its encoder.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::labels::{LabelEncoder, Classifier};
+//!
+//! let x = vec![vec![0.0, 0.1], vec![1.0, 0.9], vec![0.1, 0.0], vec![0.9, 1.0]];
+//! let y = ["small", "big", "small", "big"];
+//!
+//! let mut encoder = LabelEncoder::new();
+//! let data = encoder.data_set(&x, &y);
+//! assert_eq!(encoder.encode(&"big"), Some(vec![0.0, 1.0]));
+//!
+//! let mut classifier = Classifier::new(&[2, 3], encoder);
+//! classifier.network().learning_rate(0.05);
+//! classifier.train(&data, 5_000);
+//!
+//! let class: &str = classifier.predict(&[0.95, 0.95]);
+//! ```
+
+use serde;
+
+use data::{DataSet, Extractable};
+
+use FeedForward;
+use Transform;
+use ErrorKind;
+
+/// Encoder of class labels to one-hot vectors and back.
+/// Classes are indexed in order of their first appearance.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::labels::LabelEncoder;
+///
+/// let encoder = LabelEncoder::fit(&[3, 1, 3, 2]);
+/// assert_eq!(encoder.classes(), &[3, 1, 2]);
+/// assert_eq!(encoder.encode(&1), Some(vec![0.0, 1.0, 0.0]));
+/// assert_eq!(encoder.decode(&[0.1, -0.2, 0.7]), &2);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LabelEncoder<L> {
+    classes: Vec<L>,
+    frozen: bool,
+}
+
+impl<L> LabelEncoder<L> where L: PartialEq + Clone {
+    /// Create encoder without classes.
+    pub fn new() -> LabelEncoder<L> {
+        LabelEncoder {classes: Vec::new(), frozen: false}
+    }
+
+    /// Create encoder of all distinct labels.
+    ///
+    /// * `labels: &[L]` - labels of classes, they may repeat.
+    pub fn fit(labels: &[L]) -> LabelEncoder<L> {
+        let mut encoder = LabelEncoder::new();
+        for label in labels.iter(){
+            encoder.push(label);
+        }
+        encoder
+    }
+
+    /// Add class if it is not known yet.
+    ///
+    /// * `label: &L` - label of class;
+    /// * `return -> usize` - index of class.
+    ///
+    /// # Panics
+    ///
+    /// If class is unknown and encoder is frozen.
+    pub fn push(&mut self, label: &L) -> usize {
+        match self.index(label) {
+            Some(i) => i,
+            None => {
+                assert!(!self.frozen, "encoder is frozen, new class cannot be added");
+                self.classes.push(label.clone());
+                self.classes.len() - 1
+            }
+        }
+    }
+
+    /// Get index of class.
+    ///
+    /// * `label: &L` - label of class;
+    /// * `return -> Option<usize>` - index of class or `None` if class is unknown.
+    pub fn index(&self, label: &L) -> Option<usize> {
+        self.classes.iter().position(|c| c == label)
+    }
+
+    /// Get known classes in order of their indices.
+    pub fn classes(&self) -> &[L] {
+        &self.classes
+    }
+
+    /// Forbid adding of new classes. Encoder is frozen when `Classifier` is
+    /// created from it, since its network has one output per known class.
+    ///
+    /// * `return -> &mut LabelEncoder<L>` - link on the current struct.
+    pub fn freeze(&mut self) -> &mut LabelEncoder<L> {
+        self.frozen = true;
+        self
+    }
+
+    /// Check whether adding of new classes is forbidden.
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Get amount of known classes.
+    pub fn len(&self) -> usize {
+        self.classes.len()
+    }
+
+    /// Check whether encoder has no classes.
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+
+    /// Encode label to one-hot vector.
+    ///
+    /// * `label: &L` - label of class;
+    /// * `return -> Option<Vec<f64>>` - vector with `1` at the index of class
+    ///   or `None` if class is unknown.
+    pub fn encode(&self, label: &L) -> Option<Vec<f64>> {
+        self.index(label).map(|i| {
+            let mut v = vec![0.0; self.classes.len()];
+            v[i] = 1.0;
+            v
+        })
+    }
+
+    /// Decode output of network to class label, i.e. find the class
+    /// at the index of maximal element.
+    ///
+    /// * `y: &[f64]` - output of network;
+    /// * `return -> &L` - label of class.
+    pub fn decode(&self, y: &[f64]) -> &L {
+        let mut best = 0;
+        for i in 1..y.len(){
+            if y[i] > y[best] {
+                best = i;
+            }
+        }
+        &self.classes[best]
+    }
+
+    /// Create `DataSet` with one-hot encoded labels as expected outputs.
+    /// Unknown classes are added to encoder.
+    ///
+    /// * `x: &[Vec<f64>]` - input vectors;
+    /// * `labels: &[L]` - label of each input vector;
+    /// * `return -> DataSet` - data for training of network.
+    ///
+    /// # Panics
+    ///
+    /// If encoder is frozen and some class is unknown.
+    pub fn data_set(&mut self, x: &[Vec<f64>], labels: &[L]) -> DataSet {
+        assert_eq!(x.len(), labels.len(), "each input vector must have its label");
+
+        for label in labels.iter(){
+            self.push(label);
+        }
+
+        let mut data = DataSet::new();
+        for (x, label) in x.iter().zip(labels.iter()){
+            data.push(x, &self.encode(label).unwrap());
+        }
+        data
+    }
+}
+
+impl<L> Default for LabelEncoder<L> where L: PartialEq + Clone {
+    fn default() -> LabelEncoder<L> {
+        LabelEncoder::new()
+    }
+}
+
+impl<L> Transform for LabelEncoder<L> where L: serde::Serialize + for<'de> serde::Deserialize<'de> {}
+
+/// Classifier which bundles `FeedForward` network with encoder of its classes,
+/// so it is trained on labels and predicts labels. It is saved and loaded
+/// by `neuroflow::io` as a single unit.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::labels::{LabelEncoder, Classifier};
+/// use neuroflow::io;
+///
+/// let encoder = LabelEncoder::fit(&["a".to_string(), "b".to_string()]);
+/// let mut classifier = Classifier::new(&[2, 3], encoder);
+/// classifier.fit(&[0.0, 1.0], &"b".to_string());
+///
+/// io::save(&mut classifier, "classifier.flow").unwrap();
+/// let mut restored: Classifier<String> = io::load("classifier.flow").unwrap();
+/// # std::fs::remove_file("classifier.flow").unwrap();
+/// assert_eq!(restored.predict(&[0.0, 1.0]), classifier.predict(&[0.0, 1.0]));
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct Classifier<L> {
+    nn: FeedForward,
+    encoder: LabelEncoder<L>,
+}
+
+impl<L> Classifier<L> where L: PartialEq + Clone {
+    /// The constructor of `Classifier` struct.
+    ///
+    /// * `architecture: &[i32]` - the architecture of network without output layer.
+    ///   Output layer has one neuron per class of encoder;
+    /// * `encoder: LabelEncoder<L>` - encoder with known classes, it is frozen;
+    /// * `return` - `Classifier` struct.
+    pub fn new(architecture: &[i32], mut encoder: LabelEncoder<L>) -> Classifier<L> {
+        encoder.freeze();
+        let mut arch = architecture.to_vec();
+        arch.push(encoder.len() as i32);
+
+        Classifier {nn: FeedForward::new(&arch), encoder}
+    }
+
+    /// Create classifier from already trained network.
+    ///
+    /// * `nn: FeedForward` - network with one output per class of encoder;
+    /// * `encoder: LabelEncoder<L>` - encoder of classes, it is frozen;
+    /// * `return` - `Classifier` struct.
+    pub fn with_network(nn: FeedForward, mut encoder: LabelEncoder<L>) -> Classifier<L> {
+        assert_eq!(nn.layers[nn.layers.len() - 1].y.len(), encoder.len(),
+                   "network must have one output per class");
+        encoder.freeze();
+        Classifier {nn, encoder}
+    }
+
+    /// Get network of classifier, e.g. in order to set its parameters.
+    pub fn network(&mut self) -> &mut FeedForward {
+        &mut self.nn
+    }
+
+    /// Get encoder of classifier.
+    pub fn encoder(&self) -> &LabelEncoder<L> {
+        &self.encoder
+    }
+
+    /// Train network by data with one-hot encoded expected outputs.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `iterations: i64` - iterations count.
+    pub fn train<T>(&mut self, data: &T, iterations: i64) where T: Extractable{
+        self.nn.train(data, iterations);
+    }
+
+    /// Train network by single input vector and its label.
+    ///
+    /// * `x: &[f64]` - input vector;
+    /// * `label: &L` - label of class.
+    ///
+    /// # Panics
+    ///
+    /// If class is unknown to encoder.
+    pub fn fit(&mut self, x: &[f64], label: &L) {
+        let d = self.encoder.encode(label).expect("unknown class");
+        self.nn.fit(x, &d);
+    }
+
+    /// Calculate output of network.
+    ///
+    /// * `x: &[f64]` - input vector;
+    /// * `return -> &[f64]` - score of each class.
+    pub fn calc(&mut self, x: &[f64]) -> &[f64] {
+        self.nn.calc(x)
+    }
+
+    /// Predict class of input vector.
+    ///
+    /// * `x: &[f64]` - input vector;
+    /// * `return -> &L` - label of the most probable class.
+    pub fn predict(&mut self, x: &[f64]) -> &L {
+        let y = self.nn.calc(x);
+        self.encoder.decode(y)
+    }
+}
+
+impl<L> Transform for Classifier<L> where L: serde::Serialize + for<'de> serde::Deserialize<'de> {
+    fn before(&mut self){
+        self.nn.before();
+    }
+
+    fn after(&mut self) -> Result<(), ErrorKind>{
+        self.nn.after()
+    }
+}
@@ -118,6 +118,23 @@ pub mod activators;
 pub mod estimators;
 pub mod data;
 pub mod io;
+pub mod recurrent;
+pub mod embedding;
+pub mod attention;
+pub mod autoencoder;
+pub mod rbf;
+pub mod som;
+pub mod hopfield;
+pub mod pruning;
+pub mod growth;
+pub mod quantization;
+pub mod ensemble;
+pub mod search;
+pub mod validation;
+pub mod metrics;
+pub mod labels;
+
+mod math;
 
 extern crate rand;
 extern crate serde;
@@ -130,6 +147,7 @@ extern crate serde_derive;
 
 use std::fmt;
 use std::default::Default;
+use std::sync::Arc;
 
 use data::Extractable;
 
@@ -139,7 +157,11 @@ pub enum ErrorKind {
     IO(std::io::Error),
     Encoding(bincode::Error),
     Json(serde_json::Error),
-    StdError(Box<dyn std::error::Error>)
+    StdError(Box<dyn std::error::Error>),
+    Singular,
+    Layer(usize),
+    Dimension { expected: usize, found: usize },
+    Activation(activators::Type)
 }
 
 /// The struct that points different fields of network.
@@ -159,32 +181,73 @@ enum Field {
 ///
 /// Necessity of this trait can be easily described when you restore `FeedForward` instance
 /// by `neuroflow::io::load` function. It calls `after` method in order to adjust
-/// activation function of neural network.
+/// activation function of neural network and fails if the function cannot be restored.
 pub trait Transform: serde::Serialize + for <'de> serde::Deserialize<'de>{
     /// The method that should be called before neural network transformation
     fn before(&mut self){}
 
     /// The method that should be called after neural network transformation
-    fn after(&mut self){}
+    fn after(&mut self) -> Result<(), ErrorKind>{
+        Ok(())
+    }
 }
 
 /// Struct `Layer` represents single layer of network.
 /// It is private and should not be used directly.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct Layer {
     v: Vec<f64>,
     y: Vec<f64>,
     delta: Vec<f64>,
     prev_delta: Vec<f64>,
     w: Vec<Vec<f64>>,
+    mask: Vec<Vec<bool>>,
+    frozen: bool,
+    params: Vec<f64>,
+    params_delta: Vec<f64>,
 }
 
 /// This struct is a container for chosen activation function and its derivative.
 /// It is useful when in network's serialization in order to skip function
 /// in serialization
+#[derive(Clone)]
 struct ActivationContainer{
+    func: Arc<dyn activators::Activation>,
+    param: Option<Parametric>
+}
+
+/// Built-in activation function which calculates its derivative
+/// from output of function if it is possible
+struct Builtin{
     func: fn(f64) -> f64,
-    der: fn(f64) -> f64
+    der: fn(f64) -> f64,
+    der_output: Option<fn(f64) -> f64>
+}
+
+impl activators::Activation for Builtin{
+    fn value(&self, x: f64) -> f64{
+        (self.func)(x)
+    }
+
+    fn derivative(&self, x: f64) -> f64{
+        (self.der)(x)
+    }
+
+    fn derivative_from_output(&self, x: f64, y: f64) -> f64{
+        match self.der_output {
+            Some(der) => der(y),
+            None => (self.der)(x)
+        }
+    }
+}
+
+/// Activation function with trainable parameter, its derivative by argument
+/// and its derivative by parameter
+#[derive(Clone)]
+struct Parametric{
+    func: fn(f64, f64) -> f64,
+    der: fn(f64, f64) -> f64,
+    grad: fn(f64, f64) -> f64
 }
 
 /// Feed Forward (multilayer perceptron) neural network that is trained
@@ -245,7 +308,7 @@ struct ActivationContainer{
 /// let d: Vec<f64> = nn.calc(&[1.02]).to_vec();
 /// ```
 ///
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct FeedForward {
     layers: Vec<Layer>,
     learn_rate: f64,
@@ -260,7 +323,8 @@ pub struct FeedForward {
 
 impl Layer {
     fn new(amount: i32, input: i32) -> Layer {
-        let mut nl = Layer {v: vec![], y: vec![], delta: vec![], prev_delta: vec![], w: Vec::new()};
+        let mut nl = Layer {v: vec![], y: vec![], delta: vec![], prev_delta: vec![], w: Vec::new(), mask: Vec::new(), frozen: false,
+            params: Vec::new(), params_delta: Vec::new()};
         let mut v: Vec<f64>;
         for _ in 0..amount {
             nl.y.push(0.0);
@@ -274,7 +338,7 @@ impl Layer {
 
             nl.w.push(v);
         }
-        return nl;
+        nl
     }
 
     fn bind(&mut self, index: usize){
@@ -283,12 +347,19 @@ impl Layer {
         self.delta.insert(index, 0.0);
 
         let mut v: Vec<f64> = Vec::new();
-        let len = self.w[index].len();
+        let len = self.w[0].len();
 
         for _ in 0..len{
             v.push(2f64 * rand::random::<f64>() - 1f64);
         }
         self.w.insert(index, v);
+
+        if !self.prev_delta.is_empty() {
+            self.prev_delta.insert(index, 0.0);
+        }
+        if !self.mask.is_empty() {
+            self.mask.insert(index, vec![true; len]);
+        }
     }
 
     fn unbind(&mut self, index: usize){
@@ -296,6 +367,74 @@ impl Layer {
         self.y.remove(index);
         self.delta.remove(index);
         self.w.remove(index);
+
+        if index < self.prev_delta.len() {
+            self.prev_delta.remove(index);
+        }
+        if !self.mask.is_empty() {
+            self.mask.remove(index);
+        }
+        if !self.params.is_empty() {
+            self.params.remove(index);
+            self.params_delta.remove(index);
+        }
+    }
+
+    /// Remove weights of connections with neuron `index` of previous layer
+    fn remove_input(&mut self, index: usize){
+        for i in 0..self.w.len(){
+            self.w[i].remove(index + 1);
+            if !self.mask.is_empty() {
+                self.mask[i].remove(index + 1);
+            }
+        }
+    }
+
+    /// Add zero weights of connections with new neuron `index` of previous layer
+    fn insert_input(&mut self, index: usize){
+        for i in 0..self.w.len(){
+            self.w[i].insert(index + 1, 0.0);
+            if !self.mask.is_empty() {
+                self.mask[i].insert(index + 1, true);
+            }
+        }
+    }
+
+    /// Check whether weight is pruned and must not be trained
+    fn is_pruned(&self, i: usize, k: usize) -> bool {
+        !self.mask.is_empty() && !self.mask[i][k]
+    }
+
+    /// Create layer of `amount` neurons with identity weights and zero biases
+    /// disturbed by small noise
+    fn identity(amount: usize) -> Layer {
+        let mut nl = Layer::new(amount as i32, amount as i32);
+        for i in 0..amount{
+            for k in 0..amount + 1{
+                let target = if k == i + 1 {1.0} else {0.0};
+                nl.w[i][k] = target + 0.02 * rand::random::<f64>() - 0.01;
+            }
+        }
+        nl
+    }
+
+    /// Compose this layer with the next one supposing that output of this layer
+    /// is linear. Resulting layer has inputs of this layer and outputs of the next one.
+    fn merge(&self, next: &Layer) -> Layer {
+        let inputs = self.w[0].len();
+        let mut nl = Layer::new(next.w.len() as i32, inputs as i32 - 1);
+
+        for i in 0..next.w.len(){
+            for m in 0..inputs{
+                let mut sum = if m == 0 {next.w[i][0]} else {0.0};
+                for k in 0..self.w.len(){
+                    sum += next.w[i][k + 1] * self.w[k][m];
+                }
+                nl.w[i][m] = sum;
+            }
+        }
+        nl.frozen = next.frozen;
+        nl
     }
 }
 
@@ -303,11 +442,11 @@ impl FeedForward {
     /// The constructor of `FeedForward` struct
     ///
     /// * `architecture: &[i32]` - the architecture of network where each
-    /// element in slice represents amount of neurons in this layer.
-    /// First element in slice is amount of neurons in input layer
-    /// and the last one is amount of neurons in output layer.
-    /// Denote, that vector of input data must have the equal length as input
-    /// layer of FeedForward neural network (the same is for expected output vector).
+    ///   element in slice represents amount of neurons in this layer.
+    ///   First element in slice is amount of neurons in input layer
+    ///   and the last one is amount of neurons in output layer.
+    ///   Denote, that vector of input data must have the equal length as input
+    ///   layer of FeedForward neural network (the same is for expected output vector).
     ///
     /// * `return` - `FeedForward` struct
     /// # Example
@@ -320,31 +459,39 @@ impl FeedForward {
     pub fn new(architecture: &[i32]) -> FeedForward {
         let mut nn = FeedForward {learn_rate: 0.1, momentum: 0.1, error: 0.0,
             layers: Vec::new(),
-            act: ActivationContainer{func: activators::tanh, der: activators::der_tanh},
+            act: ActivationContainer::default(),
             act_type: activators::Type::Tanh};
 
         for i in 1..architecture.len() {
             nn.layers.push(Layer::new(architecture[i], architecture[i - 1]))
         }
 
-        return nn;
+        nn
     }
 
-    fn forward(&mut self, x: &Vec<f64>){
+    #[allow(clippy::needless_range_loop)]
+    fn forward(&mut self, x: &[f64]){
         let mut sum: f64;
 
-        for j in 0..self.layers.len(){
-            if j == 0{
-                for i in 0..self.layers[j].v.len(){
-                    sum = 0.0;
-                    for k in 0..x.len(){
-                        sum += self.layers[j].w[i][k] * x[k];
-                    }
-                    self.layers[j].v[i] = sum;
-                    self.layers[j].y[i] = (self.act.func)(sum);
-                }
+        for i in 0..self.layers[0].v.len(){
+            sum = 0.0;
+            for k in 0..x.len(){
+                sum += self.layers[0].w[i][k] * x[k];
             }
-            else if j == self.layers.len() - 1{
+            self.layers[0].v[i] = sum;
+            self.layers[0].y[i] = self.activate(0, i, sum);
+        }
+
+        self.propagate(1);
+    }
+
+    /// Propagate signal through layers starting from layer with index `start`.
+    /// Output of the previous layer must be already calculated.
+    fn propagate(&mut self, start: usize){
+        let mut sum: f64;
+
+        for j in start..self.layers.len(){
+            if j == self.layers.len() - 1{
                 for i in 0..self.layers[j].v.len(){
                     sum = self.layers[j].w[i][0];
                     for k in 0..self.layers[j - 1].y.len(){
@@ -361,13 +508,14 @@ impl FeedForward {
                         sum += self.layers[j].w[i][k + 1] * self.layers[j - 1].y[k];
                     }
                     self.layers[j].v[i] = sum;
-                    self.layers[j].y[i] = (self.act.func)(sum);
+                    self.layers[j].y[i] = self.activate(j, i, sum);
                 }
             }
         }
     }
 
-    fn backward(&mut self, d: &Vec<f64>){
+    #[allow(clippy::needless_range_loop)]
+    fn backward(&mut self, d: &[f64]){
         let mut sum: f64;
 
         for j in (0..self.layers.len()).rev(){
@@ -375,8 +523,12 @@ impl FeedForward {
             if j == self.layers.len() - 1{
                 self.error = 0.0;
                 for i in 0..self.layers[j].y.len(){
-                    self.layers[j].delta[i] = (d[i] - self.layers[j].y[i])* (self.act.der)(self.layers[j].v[i]);
+                    self.layers[j].delta[i] = (d[i] - self.layers[j].y[i])* self.derivative(j, i);
                     self.error += 0.5 * (d[i] - self.layers[j].y[i]).powi(2);
+                    // output layer is activated only if it is the only one
+                    if j == 0 {
+                        self.gradient(j, i, d[i] - self.layers[j].y[i]);
+                    }
                 }
             } else {
                 for i in 0..self.layers[j].delta.len(){
@@ -384,16 +536,24 @@ impl FeedForward {
                     for k in 0..self.layers[j + 1].delta.len(){
                         sum += self.layers[j + 1].delta[k] * self.layers[j + 1].w[k][i + 1];
                     }
-                    self.layers[j].delta[i] = (self.act.der)(self.layers[j].v[i]) * sum;
+                    self.layers[j].delta[i] = self.derivative(j, i) * sum;
+                    self.gradient(j, i, sum);
                 }
             }
         }
     }
 
-    fn update(&mut self, x: &Vec<f64>){
+    #[allow(clippy::needless_range_loop)]
+    fn update(&mut self, x: &[f64]){
         for j in 0..self.layers.len(){
+            if self.layers[j].frozen {
+                continue;
+            }
             for i in 0..self.layers[j].w.len(){
                 for k in 0..self.layers[j].w[i].len(){
+                    if self.layers[j].is_pruned(i, k) {
+                        continue;
+                    }
                     if j == 0 {
                         self.layers[j].w[i][k] += self.learn_rate * self.layers[j].delta[i]*x[k];
                     } else {
@@ -406,11 +566,81 @@ impl FeedForward {
                     self.layers[j].w[i][k] += self.momentum * self.layers[j].prev_delta[i];
                 }
             }
+            for i in 0..self.layers[j].params.len(){
+                self.layers[j].params[i] += self.learn_rate * self.layers[j].params_delta[i];
+            }
+        }
+    }
+
+    /// Activation function of neuron `i` of layer `j` applied to `v`
+    fn activate(&self, j: usize, i: usize, v: f64) -> f64{
+        match self.act.param {
+            Some(ref p) if !self.layers[j].params.is_empty() => (p.func)(v, self.layers[j].params[i]),
+            _ => self.act.func.value(v)
+        }
+    }
+
+    /// Derivative of activation function of neuron `i` of layer `j` at its induced field
+    fn derivative(&self, j: usize, i: usize) -> f64{
+        let v = self.layers[j].v[i];
+        match self.act.param {
+            Some(ref p) if !self.layers[j].params.is_empty() => (p.der)(v, self.layers[j].params[i]),
+            // output of linear layer is not a value of activation function
+            _ if j > 0 && j == self.layers.len() - 1 => self.act.func.derivative(v),
+            _ => self.act.func.derivative_from_output(v, self.layers[j].y[i])
+        }
+    }
+
+    /// Store local gradient of error by parameter of activation function
+    /// of neuron `i` of layer `j`, where `e` is error signal of the neuron
+    fn gradient(&mut self, j: usize, i: usize, e: f64){
+        if let Some(ref p) = self.act.param {
+            let layer = &mut self.layers[j];
+            if !layer.params.is_empty() {
+                layer.params_delta[i] = e * (p.grad)(layer.v[i], layer.params[i]);
+            }
         }
     }
 
+    /// Initialize parameters of activation function in layers which do not
+    /// have them yet or remove parameters if activation function has none.
+    /// Linear output layer never has parameters.
+    fn init_params(&mut self){
+        let initial = self.act_type.parameter();
+        let last = self.layers.len() - 1;
+        for (j, layer) in self.layers.iter_mut().enumerate(){
+            match initial {
+                Some(a) if j == 0 || j < last => if layer.params.is_empty() {
+                    layer.params = vec![a; layer.y.len()];
+                    layer.params_delta = vec![0.0; layer.y.len()];
+                },
+                _ => {
+                    layer.params.clear();
+                    layer.params_delta.clear();
+                }
+            }
+        }
+    }
+
+    /// Local gradient of error by the input vector (without bias element).
+    /// It must be called after `backward`. Sign is the same as for deltas,
+    /// i.e. moving input along it decreases the error.
+    #[allow(clippy::needless_range_loop)]
+    fn input_delta(&self) -> Vec<f64>{
+        let first = &self.layers[0];
+        let mut res = vec![0.0; first.w[0].len() - 1];
+
+        for i in 0..first.delta.len(){
+            for k in 0..res.len(){
+                res[k] += first.delta[i] * first.w[i][k + 1];
+            }
+        }
+        res
+    }
+
     /// Bind a new neuron to layer. It initializes neuron with
-    /// random weights.
+    /// random weights. Weights of connections of the new neuron with the next
+    /// layer are initialized by zeros, so output of network is not changed.
     ///
     /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
     /// * `neuron: usize` - index of neuron. NOTE, neurons indexing in layer starts from 0!
@@ -424,9 +654,18 @@ impl FeedForward {
     /// ```
     pub fn bind(&mut self, layer: usize, neuron: usize){
         self.layers[layer - 1].bind(neuron);
+        if !self.layers[layer - 1].params.is_empty() {
+            let a = self.act_type.parameter().unwrap();
+            self.layers[layer - 1].params.insert(neuron, a);
+            self.layers[layer - 1].params_delta.insert(neuron, 0.0);
+        }
+        if layer < self.layers.len() {
+            self.layers[layer].insert_input(neuron);
+        }
     }
 
-    /// Unbind neuron from layer.
+    /// Unbind neuron from layer. Weights of connections of this neuron with
+    /// the next layer are removed as well.
     ///
     /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
     /// * `neuron: usize` - index of neuron. NOTE, neurons indexing in layer starts from 0!
@@ -439,7 +678,12 @@ impl FeedForward {
     /// nn.unbind(2, 0);
     /// ```
     pub fn unbind(&mut self, layer: usize, neuron: usize){
+        assert!(self.layers[layer - 1].y.len() > 1, "cannot unbind the last neuron of layer");
+
         self.layers[layer - 1].unbind(neuron);
+        if layer < self.layers.len() {
+            self.layers[layer].remove_input(neuron);
+        }
     }
 
     /// Train neural network by bulked data.
@@ -459,7 +703,7 @@ impl FeedForward {
     pub fn train<T>(&mut self, data: &T, iterations: i64) where T: Extractable{
         for _ in 0..iterations{
             let (x, y) = data.rand();
-            self.fit(&x, &y);
+            self.fit(x, y);
         }
     }
 
@@ -511,37 +755,62 @@ impl FeedForward {
 
     /// Choose activation function. `Note` that if you pass `activators::Type::Custom`
     /// as argument of this method, the default value (`activators::Type::Tanh`) will
-    /// be used.
+    /// be used. Functions with parameters, e.g. `activators::Type::PRelu`, have
+    /// their own parameter for each activated neuron which is trained along with
+    /// weights. Linear output layer has no parameters.
     ///
     /// * `func: neuroflow::activators::Type` - enum element that indicates which
-    /// function to use;
+    ///   function to use;
     /// * `return -> &mut FeedForward` - link on the current struct.
+    ///
+    /// # Panics
+    ///
+    /// If function of `activators::Type::Named` is not registered. Use `try_activation`
+    /// when name of function comes from user input.
     pub fn activation(&mut self, func: activators::Type) -> &mut FeedForward{
-        match func{
-            activators::Type::Sigmoid => {
-                self.act_type = activators::Type::Sigmoid;
-                self.act.func = activators::sigm;
-                self.act.der = activators::der_sigm;
-            }
-            activators::Type::Tanh | activators::Type::Custom => {
-                self.act_type = activators::Type::Tanh;
-                self.act.func = activators::tanh;
-                self.act.der = activators::der_tanh;
-            }
-            activators::Type::Relu => {
-                self.act_type = activators::Type::Relu;
-                self.act.func = activators::relu;
-                self.act.der = activators::der_relu;
+        self.act = ActivationContainer::from_type(&func);
+        self.act_type = match func {
+            activators::Type::Custom => activators::Type::Tanh,
+            t => t
+        };
+        self.init_params();
+        self
+    }
+
+    /// Choose activation function like `activation` but fail instead of panic
+    /// if function of `activators::Type::Named` is not registered.
+    ///
+    /// * `func: neuroflow::activators::Type` - enum element that indicates which
+    ///   function to use;
+    /// * `return -> Result<&mut FeedForward, ErrorKind>` - link on the current struct
+    ///   or `ErrorKind::Activation` if function is not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// use neuroflow::activators::Type;
+    ///
+    /// let mut nn = FeedForward::new(&[1, 3, 2]);
+    /// assert!(nn.try_activation(Type::Named("unknown".to_string())).is_err());
+    /// assert!(nn.try_activation(Type::Relu).is_ok());
+    /// ```
+    pub fn try_activation(&mut self, func: activators::Type) -> Result<&mut FeedForward, ErrorKind>{
+        if let activators::Type::Named(ref name) = func {
+            if activators::registered(name).is_none() {
+                return Err(ErrorKind::Activation(func.clone()));
             }
         }
-        self
+        Ok(self.activation(func))
     }
 
     /// Set custom activation function and its derivative.
-    /// Activation type is set to `activators::Type::Custom`.
+    /// Activation type is set to `activators::Type::Custom`, so network cannot be
+    /// restored by `neuroflow::io::load`. In order to save network with custom function
+    /// register it by `activators::register` and use `activators::Type::Named`.
     ///
-    /// * `func: fn(f64) -> f64` - activation function to be set;
-    /// * `der: fn(f64) -> f64` - derivative of activation function;
+    /// * `func: F` - activation function to be set, function or closure;
+    /// * `der: D` - derivative of activation function, function or closure;
     /// * `return -> &mut FeedForward` - link on the current struct.
     ///
     /// # Warning
@@ -565,11 +834,21 @@ impl FeedForward {
     /// let mut nn = FeedForward::new(&[1, 3, 2]);
     /// nn.custom_activation(sigmoid, der_sigmoid);
     /// ```
-    pub fn custom_activation(&mut self, func: fn(f64) -> f64, der: fn(f64) -> f64) -> &mut FeedForward{
+    pub fn custom_activation<F, D>(&mut self, func: F, der: D) -> &mut FeedForward
+        where F: Fn(f64) -> f64 + Send + Sync + 'static, D: Fn(f64) -> f64 + Send + Sync + 'static{
+        self.activator((func, der))
+    }
+
+    /// Set custom activation function implementing `activators::Activation` trait.
+    /// Activation type is set to `activators::Type::Custom` as by `custom_activation`.
+    ///
+    /// * `activation: A` - activation function to be set;
+    /// * `return -> &mut FeedForward` - link on the current struct.
+    pub fn activator<A>(&mut self, activation: A) -> &mut FeedForward where A: activators::Activation + 'static{
         self.act_type = activators::Type::Custom;
 
-        self.act.func = func;
-        self.act.der = der;
+        self.act = ActivationContainer{func: Arc::new(activation), param: None};
+        self.init_params();
 
         self
     }
@@ -614,39 +893,445 @@ impl FeedForward {
     pub fn get_error(&self) -> f64{
         self.error
     }
+
+    /// Get the architecture of network in the same form as it is passed to
+    /// the constructor `new`.
+    ///
+    /// * `return -> Vec<i32>` - amount of neurons in each layer including input one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// let nn = FeedForward::new(&[1, 3, 2]);
+    /// assert_eq!(nn.architecture(), vec![1, 3, 2]);
+    /// ```
+    pub fn architecture(&self) -> Vec<i32>{
+        let mut arch = vec![(self.layers[0].w[0].len() - 1) as i32];
+        for layer in self.layers.iter(){
+            arch.push(layer.y.len() as i32);
+        }
+        arch
+    }
+
+    /// Get amount of layers excluding input one. Layers are indexed
+    /// from `1` to `layer_count()`.
+    ///
+    /// * `return -> usize` - amount of layers.
+    pub fn layer_count(&self) -> usize{
+        self.layers.len()
+    }
+
+    /// Get weights of layer. Row `i` contains weights of connections of
+    /// neuron `i` with each neuron of previous layer. Biases are not included.
+    ///
+    /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+    /// * `return -> Vec<Vec<f64>>` - matrix of weights.
+    ///
+    /// # Panics
+    ///
+    /// If layer is out of range `[1, layer_count()]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// let nn = FeedForward::new(&[1, 3, 2]);
+    /// let w = nn.weights(2);
+    /// assert_eq!((w.len(), w[0].len()), (2, 3));
+    /// ```
+    pub fn weights(&self, layer: usize) -> Vec<Vec<f64>>{
+        self.layers[layer - 1].w.iter().map(|w| w[1..].to_vec()).collect()
+    }
+
+    /// Get biases of neurons of layer.
+    ///
+    /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+    /// * `return -> Vec<f64>` - biases.
+    ///
+    /// # Panics
+    ///
+    /// If layer is out of range `[1, layer_count()]`.
+    pub fn biases(&self, layer: usize) -> Vec<f64>{
+        self.layers[layer - 1].w.iter().map(|w| w[0]).collect()
+    }
+
+    /// Get outputs of neurons of layer calculated by the last call of
+    /// `calc` or `fit`.
+    ///
+    /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+    /// * `return -> &[f64]` - activated outputs of layer.
+    ///
+    /// # Panics
+    ///
+    /// If layer is out of range `[1, layer_count()]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// let mut nn = FeedForward::new(&[1, 3, 2]);
+    /// nn.calc(&[0.5]);
+    /// let hidden: Vec<f64> = nn.activations(1).to_vec();
+    /// ```
+    pub fn activations(&self, layer: usize) -> &[f64]{
+        &self.layers[layer - 1].y
+    }
+
+    /// Get trainable parameters of activation function of each neuron of layer,
+    /// e.g. slopes of `activators::Type::PRelu`.
+    ///
+    /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+    /// * `return -> &[f64]` - parameters or empty slice if activation function
+    ///   has no parameters or layer is linear output layer.
+    ///
+    /// # Panics
+    ///
+    /// If layer is out of range `[1, layer_count()]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// use neuroflow::activators::Type;
+    ///
+    /// let mut nn = FeedForward::new(&[1, 3, 2]);
+    /// nn.activation(Type::PRelu);
+    /// assert_eq!(nn.activation_params(1), &[0.25, 0.25, 0.25]);
+    /// ```
+    pub fn activation_params(&self, layer: usize) -> &[f64]{
+        &self.layers[layer - 1].params
+    }
+
+    /// Set weights of layer, e.g. loaded from external source. Biases are kept.
+    ///
+    /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+    /// * `weights: &[Vec<f64>]` - matrix of weights in the same form as it is
+    ///   returned by `weights` method;
+    /// * `return -> Result<(), ErrorKind>` - `ErrorKind::Layer` if there is no such layer
+    ///   and `ErrorKind::Dimension` if matrix has wrong size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// let mut nn = FeedForward::new(&[2, 1]);
+    /// nn.set_weights(1, &[vec![0.5, -0.5]]).unwrap();
+    /// assert!(nn.set_weights(1, &[vec![0.5]]).is_err());
+    /// ```
+    pub fn set_weights(&mut self, layer: usize, weights: &[Vec<f64>]) -> Result<(), ErrorKind>{
+        self.check_layer(layer, weights.len())?;
+
+        let inputs = self.layers[layer - 1].w[0].len() - 1;
+        for row in weights.iter(){
+            if row.len() != inputs {
+                return Err(ErrorKind::Dimension {expected: inputs, found: row.len()});
+            }
+        }
+
+        for (w, row) in self.layers[layer - 1].w.iter_mut().zip(weights.iter()){
+            w[1..].copy_from_slice(row);
+        }
+        Ok(())
+    }
+
+    /// Set biases of neurons of layer.
+    ///
+    /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+    /// * `biases: &[f64]` - bias of each neuron;
+    /// * `return -> Result<(), ErrorKind>` - `ErrorKind::Layer` if there is no such layer
+    ///   and `ErrorKind::Dimension` if amount of biases differs from amount of neurons.
+    pub fn set_biases(&mut self, layer: usize, biases: &[f64]) -> Result<(), ErrorKind>{
+        self.check_layer(layer, biases.len())?;
+
+        for (w, b) in self.layers[layer - 1].w.iter_mut().zip(biases.iter()){
+            w[0] = *b;
+        }
+        Ok(())
+    }
+
+    /// Freeze layer, so its weights and biases are not changed while training.
+    /// It is useful for fine-tuning of pretrained network on new data.
+    ///
+    /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+    ///
+    /// # Panics
+    ///
+    /// If layer is out of range `[1, layer_count()]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// let mut nn = FeedForward::new(&[2, 4, 3, 1]);
+    /// nn.freeze(1).freeze(2);
+    /// assert!(!nn.is_trainable(1));
+    /// ```
+    pub fn freeze(&mut self, layer: usize) -> &mut FeedForward{
+        self.layers[layer - 1].frozen = true;
+        self
+    }
+
+    /// Make frozen layer trainable again.
+    ///
+    /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+    ///
+    /// # Panics
+    ///
+    /// If layer is out of range `[1, layer_count()]`.
+    pub fn unfreeze(&mut self, layer: usize) -> &mut FeedForward{
+        self.layers[layer - 1].frozen = false;
+        self
+    }
+
+    /// Check whether layer is changed while training.
+    ///
+    /// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+    /// * `return -> bool` - `false` if layer is frozen.
+    ///
+    /// # Panics
+    ///
+    /// If layer is out of range `[1, layer_count()]`.
+    pub fn is_trainable(&self, layer: usize) -> bool{
+        !self.layers[layer - 1].frozen
+    }
+
+    /// Keep only the first layers of network and remove the others.
+    /// The last kept layer becomes output layer.
+    ///
+    /// * `layers: usize` - amount of layers to keep excluding input one.
+    ///
+    /// # Panics
+    ///
+    /// If `layers` is out of range `[1, layer_count()]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// let mut nn = FeedForward::new(&[2, 4, 3, 1]);
+    /// nn.truncate(2);
+    /// assert_eq!(nn.architecture(), vec![2, 4, 3]);
+    /// ```
+    pub fn truncate(&mut self, layers: usize) -> &mut FeedForward{
+        assert!(layers >= 1 && layers <= self.layers.len(), "network must keep from 1 to {} layers", self.layers.len());
+        self.layers.truncate(layers);
+        self.init_params();
+        self
+    }
+
+    /// Append a new output layer with random weights to network.
+    ///
+    /// * `neurons: i32` - amount of neurons in the new layer.
+    ///
+    /// # Examples
+    ///
+    /// Replace output layer of pretrained network and fine-tune only it:
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// let mut nn = FeedForward::new(&[2, 4, 3, 1]);
+    /// nn.truncate(2)
+    ///     .freeze(1)
+    ///     .freeze(2)
+    ///     .push_layer(2);
+    /// assert_eq!(nn.architecture(), vec![2, 4, 3, 2]);
+    /// assert!(nn.is_trainable(3));
+    /// ```
+    pub fn push_layer(&mut self, neurons: i32) -> &mut FeedForward{
+        let input = self.layers[self.layers.len() - 1].y.len() as i32;
+        self.layers.push(Layer::new(neurons, input));
+        self.init_params();
+        self
+    }
+
+    /// Insert a new hidden layer before layer `layer`. The new layer has as many
+    /// neurons as the previous one and is initialized near identity: identity
+    /// weights, zero biases and a small noise. Output of network is changed
+    /// only by activation function of the new layer.
+    ///
+    /// * `layer: usize` - index of the new layer. NOTE, layer indexing starts from 1!
+    ///
+    /// # Panics
+    ///
+    /// If layer is out of range `[1, layer_count()]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// let mut nn = FeedForward::new(&[2, 4, 1]);
+    /// nn.insert_layer(2);
+    /// assert_eq!(nn.architecture(), vec![2, 4, 4, 1]);
+    /// ```
+    pub fn insert_layer(&mut self, layer: usize) -> &mut FeedForward{
+        assert!(layer >= 1 && layer <= self.layers.len(), "layer must be in range [1, {}]", self.layers.len());
+
+        let amount = self.layers[layer - 1].w[0].len() - 1;
+        self.layers.insert(layer - 1, Layer::identity(amount));
+        self.init_params();
+        self
+    }
+
+    /// Remove hidden layer. Weights of the removed layer are composed with
+    /// the next layer as if the removed layer were linear, i.e. its activation
+    /// function is dropped. Hence the network keeps its behaviour exactly only
+    /// for linear activation, otherwise the result is a linear approximation
+    /// which is close while the removed layer works in the nearly linear range
+    /// of its activation function, e.g. small induced fields for `Tanh`.
+    /// The network usually needs retraining after removal.
+    ///
+    /// * `layer: usize` - index of hidden layer. NOTE, layer indexing starts from 1!
+    ///
+    /// # Panics
+    ///
+    /// If layer is out of range `[1, layer_count() - 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// let mut nn = FeedForward::new(&[2, 4, 3, 1]);
+    /// nn.remove_layer(1);
+    /// assert_eq!(nn.architecture(), vec![2, 3, 1]);
+    /// ```
+    pub fn remove_layer(&mut self, layer: usize) -> &mut FeedForward{
+        assert!(layer >= 1 && layer < self.layers.len(), "only hidden layers in range [1, {}] can be removed",
+                self.layers.len() - 1);
+
+        let removed = self.layers.remove(layer - 1);
+        self.layers[layer - 1] = removed.merge(&self.layers[layer - 1]);
+        self.init_params();
+        self
+    }
+
+    /// Compose two networks end-to-end, so output of this network feeds the `next` one.
+    /// Output layer of this network is linear, hence it is merged with the first
+    /// layer of `next` and the resulting network calculates exactly `next(self(x))`
+    /// when both networks have hidden layers.
+    /// Learning rate, momentum and activation function are taken from this network.
+    ///
+    /// * `next: &FeedForward` - the network which receives output of this one;
+    /// * `return -> Result<FeedForward, ErrorKind>` - composed network or
+    ///   `ErrorKind::Dimension` if output of this network does not fit input of `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::FeedForward;
+    /// let a = FeedForward::new(&[2, 4, 3]);
+    /// let b = FeedForward::new(&[3, 5, 1]);
+    ///
+    /// let nn = a.concat(&b).unwrap();
+    /// assert_eq!(nn.architecture(), vec![2, 4, 5, 1]);
+    /// assert!(b.concat(&a).is_err());
+    /// ```
+    pub fn concat(&self, next: &FeedForward) -> Result<FeedForward, ErrorKind>{
+        let outputs = self.layers[self.layers.len() - 1].y.len();
+        let inputs = next.layers[0].w[0].len() - 1;
+        if outputs != inputs {
+            return Err(ErrorKind::Dimension {expected: inputs, found: outputs});
+        }
+
+        let mut nn = self.clone();
+        let last = nn.layers.pop().unwrap();
+        nn.layers.push(last.merge(&next.layers[0]));
+        nn.layers.extend(next.layers[1..].iter().cloned());
+        nn.init_params();
+        Ok(nn)
+    }
+
+    /// Check that layer exists and has `neurons` neurons
+    fn check_layer(&self, layer: usize, neurons: usize) -> Result<(), ErrorKind>{
+        if layer == 0 || layer > self.layers.len() {
+            return Err(ErrorKind::Layer(layer));
+        }
+
+        let expected = self.layers[layer - 1].y.len();
+        if neurons != expected {
+            return Err(ErrorKind::Dimension {expected, found: neurons});
+        }
+        Ok(())
+    }
 }
 
 impl Transform for FeedForward{
-    fn after(&mut self){
-        match self.act_type {
-            activators::Type::Sigmoid => {
-                self.act_type = activators::Type::Sigmoid;
-                self.act.func = activators::sigm;
-                self.act.der = activators::der_sigm;
-            }
-            activators::Type::Tanh | activators::Type::Custom => {
-                self.act_type = activators::Type::Tanh;
-                self.act.func = activators::tanh;
-                self.act.der = activators::der_tanh;
-            }
-            activators::Type::Relu => {
-                self.act_type = activators::Type::Relu;
-                self.act.func = activators::relu;
-                self.act.der = activators::der_relu;
-            }
+    fn after(&mut self) -> Result<(), ErrorKind>{
+        self.act = ActivationContainer::restore(&self.act_type)?;
+        Ok(())
+    }
+}
+
+impl ActivationContainer{
+    /// Create container for the given activation type. `Note` that custom
+    /// function cannot be restored from `activators::Type::Custom`, so
+    /// the default `activators::tanh` is used instead.
+    ///
+    /// # Panics
+    ///
+    /// If function of `activators::Type::Named` is not registered.
+    fn from_type(t: &activators::Type) -> ActivationContainer {
+        match *t {
+            activators::Type::Named(ref name) => match activators::registered(name) {
+                Some(func) => ActivationContainer{func, param: None},
+                None => panic!("activation function `{}` is not registered", name)
+            },
+            activators::Type::Sigmoid => ActivationContainer::new(activators::sigm, activators::der_sigm, Some(activators::der_sigm_output)),
+            activators::Type::Tanh | activators::Type::Custom => ActivationContainer::new(activators::tanh, activators::der_tanh, Some(activators::der_tanh_output)),
+            activators::Type::Relu => ActivationContainer::new(activators::relu, activators::der_relu, Some(activators::der_relu_output)),
+            activators::Type::LeakyRelu => ActivationContainer::new(activators::leaky_relu, activators::der_leaky_relu, Some(activators::der_leaky_relu_output)),
+            activators::Type::Elu => ActivationContainer::new(activators::elu, activators::der_elu, Some(activators::der_elu_output)),
+            activators::Type::Selu => ActivationContainer::new(activators::selu, activators::der_selu, Some(activators::der_selu_output)),
+            activators::Type::Gelu => ActivationContainer::new(activators::gelu, activators::der_gelu, None),
+            activators::Type::Swish => ActivationContainer::new(activators::swish, activators::der_swish, None),
+            activators::Type::Softplus => ActivationContainer::new(activators::softplus, activators::der_softplus, None),
+            activators::Type::Softsign => ActivationContainer::new(activators::softsign, activators::der_softsign, Some(activators::der_softsign_output)),
+            activators::Type::HardSigmoid => ActivationContainer::new(activators::hard_sigm, activators::der_hard_sigm, Some(activators::der_hard_sigm_output)),
+            activators::Type::HardTanh => ActivationContainer::new(activators::hard_tanh, activators::der_hard_tanh, Some(activators::der_hard_tanh_output)),
+            activators::Type::Gaussian => ActivationContainer::new(activators::gaussian, activators::der_gaussian, None),
+            activators::Type::Identity => ActivationContainer::new(activators::identity, activators::der_identity, Some(activators::der_identity_output)),
+            activators::Type::PRelu => ActivationContainer{
+                func: Arc::new((|x| activators::prelu(x, activators::PRELU_SLOPE),
+                                |x| activators::der_prelu(x, activators::PRELU_SLOPE))),
+                param: Some(Parametric{func: activators::prelu, der: activators::der_prelu,
+                    grad: activators::der_prelu_param})
+            },
+            activators::Type::LearnableSwish => ActivationContainer{
+                func: Arc::new((activators::swish, activators::der_swish)),
+                param: Some(Parametric{func: activators::swish_beta, der: activators::der_swish_beta,
+                    grad: activators::der_swish_beta_param})
+            },
         }
     }
+
+    /// Create container for the given activation type of restored network.
+    ///
+    /// * `return -> Result<ActivationContainer, ErrorKind>` - container or
+    ///   `ErrorKind::Activation` if function is custom and not registered.
+    fn restore(t: &activators::Type) -> Result<ActivationContainer, ErrorKind> {
+        match *t {
+            activators::Type::Custom => Err(ErrorKind::Activation(t.clone())),
+            activators::Type::Named(ref name) if activators::registered(name).is_none() =>
+                Err(ErrorKind::Activation(t.clone())),
+            _ => Ok(ActivationContainer::from_type(t))
+        }
+    }
+
+    /// Create container for built-in activation function without parameters
+    fn new(func: fn(f64) -> f64, der: fn(f64) -> f64, der_output: Option<fn(f64) -> f64>) -> ActivationContainer {
+        ActivationContainer{func: Arc::new(Builtin{func, der, der_output}), param: None}
+    }
 }
 
 impl Default for ActivationContainer{
     fn default() -> ActivationContainer {
-        ActivationContainer{func: activators::tanh, der: activators::der_tanh}
+        ActivationContainer::new(activators::tanh, activators::der_tanh, Some(activators::der_tanh_output))
     }
 }
 
 impl fmt::Display for FeedForward {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result{
-        let mut buf: String = format!("**Induced field**\n");
+        let mut buf: String = "**Induced field**\n".to_string();
 
         for v in self.layers.iter(){
             for val in v.v.iter(){
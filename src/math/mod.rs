@@ -0,0 +1,104 @@
+//! Private module with small linear algebra helpers shared by
+//! the network implementations of the crate.
+
+use rand;
+
+/// Dot product of two slices of equal length
+pub fn dot(a: &[f64], b: &[f64]) -> f64 {
+    let mut sum = 0.0;
+    for i in 0..a.len(){
+        sum += a[i] * b[i];
+    }
+    sum
+}
+
+/// Multiply matrix by vector
+pub fn mul(w: &[Vec<f64>], x: &[f64]) -> Vec<f64> {
+    w.iter().map(|row| dot(row, x)).collect()
+}
+
+/// Multiply transposed matrix by vector
+pub fn mul_transposed(w: &[Vec<f64>], d: &[f64]) -> Vec<f64> {
+    let mut res = vec![0.0; w[0].len()];
+    for i in 0..w.len(){
+        for k in 0..w[i].len(){
+            res[k] += w[i][k] * d[i];
+        }
+    }
+    res
+}
+
+/// Matrix of zeros with `rows` rows and `cols` columns
+pub fn zeros(rows: usize, cols: usize) -> Vec<Vec<f64>> {
+    vec![vec![0.0; cols]; rows]
+}
+
+/// Matrix initialized by uniformly distributed random values from `[-scale, scale]`
+pub fn random(rows: usize, cols: usize, scale: f64) -> Vec<Vec<f64>> {
+    let mut m = zeros(rows, cols);
+    for row in m.iter_mut(){
+        for v in row.iter_mut(){
+            *v = scale * (2f64 * rand::random::<f64>() - 1f64);
+        }
+    }
+    m
+}
+
+/// Logistic sigmoid function `1 / (1 + e^(-x))`
+pub fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Solve the system of linear equations `a·x = b` for each column of `b` by Gaussian
+/// elimination with partial pivoting.
+///
+/// * `return` - `None` if matrix `a` is singular.
+#[allow(clippy::needless_range_loop)]
+pub fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    let n = a.len();
+
+    for c in 0..n{
+        let mut p = c;
+        for r in c + 1..n{
+            if a[r][c].abs() > a[p][c].abs(){
+                p = r;
+            }
+        }
+        if a[p][c].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(c, p);
+        b.swap(c, p);
+
+        for r in c + 1..n{
+            let f = a[r][c] / a[c][c];
+            for k in c..n{
+                a[r][k] -= f * a[c][k];
+            }
+            for k in 0..b[r].len(){
+                b[r][k] -= f * b[c][k];
+            }
+        }
+    }
+
+    let mut x = zeros(n, b[0].len());
+    for c in (0..n).rev(){
+        for k in 0..b[c].len(){
+            let mut sum = b[c][k];
+            for j in c + 1..n{
+                sum -= a[c][j] * x[j][k];
+            }
+            x[c][k] = sum / a[c][c];
+        }
+    }
+    Some(x)
+}
+
+/// Squared euclidean distance between two vectors
+pub fn distance2(a: &[f64], b: &[f64]) -> f64 {
+    let mut sum = 0.0;
+    for i in 0..a.len(){
+        sum += (a[i] - b[i]).powi(2);
+    }
+    sum
+}
@@ -0,0 +1,376 @@
+//! Module contains metrics for evaluation of `FeedForward` neural networks.
+//!
+//! Each metric is calculated by network on data that implements
+//! `neuroflow::data::Extractable` trait.
+//!
+//! Regression metrics are averaged over all outputs of network.
+//!
+//! For classification metrics the class of network output (or expected output)
+//! with several elements is the index of maximal element, i.e. one-hot encoding is
+//! supposed. Network with single output is a binary classifier: the class is `1`
+//! if output is not less than `0.5` and `0` otherwise. Outputs of network are
+//! treated as probabilities of classes in `log_loss`, `roc_auc` and `pr_auc`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::FeedForward;
+//! use neuroflow::data::DataSet;
+//! use neuroflow::metrics;
+//!
+//! let mut nn = FeedForward::new(&[2, 3, 2]);
+//! let mut data = DataSet::new();
+//! data.push(&[0.0, 1.0], &[1.0, 0.0]);
+//! data.push(&[1.0, 0.0], &[0.0, 1.0]);
+//! nn.train(&data, 1_000);
+//!
+//! println!("mse: {:.4}", metrics::mse(&mut nn, &data));
+//! println!("accuracy: {:.2}", metrics::accuracy(&mut nn, &data));
+//! println!("{:?}", metrics::confusion_matrix(&mut nn, &data));
+//! ```
+
+use data::Extractable;
+use FeedForward;
+
+/// Smallest probability used in `log_loss` in order to avoid infinite values
+const EPSILON: f64 = 1e-15;
+
+/// Way of averaging of per-class metrics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Average {
+    /// Metric is calculated for each class and then averaged
+    Macro,
+    /// True and false positives and negatives are summed over classes
+    /// and then metric is calculated
+    Micro,
+}
+
+/// Outputs of network and expected outputs for each element of data
+fn predict<T>(nn: &mut FeedForward, data: &T) -> Vec<(Vec<f64>, Vec<f64>)> where T: Extractable{
+    (0..data.len()).map(|i| {
+        let (x, d) = data.get(i);
+        (nn.calc(x).to_vec(), d.clone())
+    }).collect()
+}
+
+/// Class of output vector
+fn class(y: &[f64]) -> usize {
+    if y.len() == 1 {
+        return if y[0] >= 0.5 {1} else {0};
+    }
+
+    let mut best = 0;
+    for i in 1..y.len(){
+        if y[i] > y[best] {
+            best = i;
+        }
+    }
+    best
+}
+
+/// Amount of classes for output vector of given length
+fn classes(len: usize) -> usize {
+    if len == 1 {2} else {len}
+}
+
+/// Mean squared error.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> f64` - mean squared error.
+pub fn mse<T>(nn: &mut FeedForward, data: &T) -> f64 where T: Extractable{
+    let mut error = 0.0;
+    for (y, d) in predict(nn, data){
+        for k in 0..d.len(){
+            error += (d[k] - y[k]).powi(2) / d.len() as f64;
+        }
+    }
+    error / data.len() as f64
+}
+
+/// Root of mean squared error.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> f64` - root mean squared error.
+pub fn rmse<T>(nn: &mut FeedForward, data: &T) -> f64 where T: Extractable{
+    mse(nn, data).sqrt()
+}
+
+/// Mean absolute error.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> f64` - mean absolute error.
+pub fn mae<T>(nn: &mut FeedForward, data: &T) -> f64 where T: Extractable{
+    let mut error = 0.0;
+    for (y, d) in predict(nn, data){
+        for k in 0..d.len(){
+            error += (d[k] - y[k]).abs() / d.len() as f64;
+        }
+    }
+    error / data.len() as f64
+}
+
+/// Coefficient of determination R². It is calculated for each output
+/// and then averaged.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> f64` - coefficient of determination, `1` for perfect fit,
+///   `NaN` for empty data.
+pub fn r2<T>(nn: &mut FeedForward, data: &T) -> f64 where T: Extractable{
+    let res = predict(nn, data);
+    if res.is_empty() {
+        return f64::NAN;
+    }
+    let outputs = res[0].1.len();
+    let mut sum = 0.0;
+
+    for k in 0..outputs{
+        let mean = res.iter().map(|r| r.1[k]).sum::<f64>() / res.len() as f64;
+        let ss_res: f64 = res.iter().map(|r| (r.1[k] - r.0[k]).powi(2)).sum();
+        let ss_tot: f64 = res.iter().map(|r| (r.1[k] - mean).powi(2)).sum();
+
+        sum += if ss_tot == 0.0 {
+            if ss_res == 0.0 {1.0} else {0.0}
+        } else {
+            1.0 - ss_res / ss_tot
+        };
+    }
+    sum / outputs as f64
+}
+
+/// Mean absolute percentage error. Elements with zero expected value are skipped.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> f64` - mean absolute percentage error in percents, `NaN` if
+///   all expected values are zero.
+pub fn mape<T>(nn: &mut FeedForward, data: &T) -> f64 where T: Extractable{
+    let mut error = 0.0;
+    let mut count = 0;
+    for (y, d) in predict(nn, data){
+        for k in 0..d.len(){
+            if d[k] != 0.0 {
+                error += ((d[k] - y[k]) / d[k]).abs();
+                count += 1;
+            }
+        }
+    }
+    100.0 * error / count as f64
+}
+
+/// Confusion matrix. Element `[i][j]` is amount of elements of class `i`
+/// which are classified as class `j`.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> Vec<Vec<usize>>` - confusion matrix, empty for empty data.
+pub fn confusion_matrix<T>(nn: &mut FeedForward, data: &T) -> Vec<Vec<usize>> where T: Extractable{
+    let res = predict(nn, data);
+    if res.is_empty() {
+        return Vec::new();
+    }
+    let n = classes(res[0].1.len());
+    let mut matrix = vec![vec![0; n]; n];
+
+    for (y, d) in res.iter(){
+        matrix[class(d)][class(y)] += 1;
+    }
+    matrix
+}
+
+/// Share of correctly classified elements.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> f64` - accuracy.
+pub fn accuracy<T>(nn: &mut FeedForward, data: &T) -> f64 where T: Extractable{
+    let matrix = confusion_matrix(nn, data);
+    let correct: usize = (0..matrix.len()).map(|i| matrix[i][i]).sum();
+    correct as f64 / data.len() as f64
+}
+
+/// True positives, false positives and false negatives of each class
+fn counts(matrix: &[Vec<usize>]) -> Vec<(f64, f64, f64)> {
+    (0..matrix.len()).map(|c| {
+        let tp = matrix[c][c];
+        let fp = (0..matrix.len()).map(|i| matrix[i][c]).sum::<usize>() - tp;
+        let fn_ = matrix[c].iter().sum::<usize>() - tp;
+        (tp as f64, fp as f64, fn_ as f64)
+    }).collect()
+}
+
+/// Ratio which is `0` if denominator is zero
+fn ratio(a: f64, b: f64) -> f64 {
+    if b == 0.0 {0.0} else {a / b}
+}
+
+/// Calculate per-class metric `f(tp, fp, fn)` and average it
+fn averaged<F>(matrix: &[Vec<usize>], average: Average, f: F) -> f64 where F: Fn(f64, f64, f64) -> f64{
+    if matrix.is_empty() {
+        return f64::NAN;
+    }
+
+    let counts = counts(matrix);
+    match average {
+        Average::Macro => counts.iter().map(|c| f(c.0, c.1, c.2)).sum::<f64>() / counts.len() as f64,
+        Average::Micro => {
+            let total = counts.iter().fold((0.0, 0.0, 0.0), |s, c| (s.0 + c.0, s.1 + c.1, s.2 + c.2));
+            f(total.0, total.1, total.2)
+        }
+    }
+}
+
+/// Precision, i.e. share of correctly classified elements among elements
+/// classified as the class.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `average: Average` - way of averaging over classes;
+/// * `return -> f64` - precision.
+pub fn precision<T>(nn: &mut FeedForward, data: &T, average: Average) -> f64 where T: Extractable{
+    averaged(&confusion_matrix(nn, data), average, |tp, fp, _| ratio(tp, tp + fp))
+}
+
+/// Recall, i.e. share of correctly classified elements among elements of the class.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `average: Average` - way of averaging over classes;
+/// * `return -> f64` - recall.
+pub fn recall<T>(nn: &mut FeedForward, data: &T, average: Average) -> f64 where T: Extractable{
+    averaged(&confusion_matrix(nn, data), average, |tp, _, fn_| ratio(tp, tp + fn_))
+}
+
+/// F1 score, i.e. harmonic mean of precision and recall.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `average: Average` - way of averaging over classes;
+/// * `return -> f64` - F1 score.
+pub fn f1<T>(nn: &mut FeedForward, data: &T, average: Average) -> f64 where T: Extractable{
+    averaged(&confusion_matrix(nn, data), average, |tp, fp, fn_| ratio(2.0 * tp, 2.0 * tp + fp + fn_))
+}
+
+/// Cross-entropy loss. Outputs of network are clipped to range `[1e-15, 1 - 1e-15]`
+/// and, for several outputs, normalized to sum of `1`.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> f64` - mean log-loss.
+pub fn log_loss<T>(nn: &mut FeedForward, data: &T) -> f64 where T: Extractable{
+    let mut loss = 0.0;
+    for (y, d) in predict(nn, data){
+        let p: Vec<f64> = y.iter().map(|v| v.clamp(EPSILON, 1.0 - EPSILON)).collect();
+
+        if p.len() == 1 {
+            let t = class(&d) as f64;
+            loss -= t * p[0].ln() + (1.0 - t) * (1.0 - p[0]).ln();
+        } else {
+            let sum: f64 = p.iter().sum();
+            loss -= (p[class(&d)] / sum).ln();
+        }
+    }
+    loss / data.len() as f64
+}
+
+/// Scores of being positive and true labels for each class in one-vs-rest manner
+fn binary<T>(nn: &mut FeedForward, data: &T) -> Vec<Vec<(f64, bool)>> where T: Extractable{
+    let res = predict(nn, data);
+    if res.is_empty() {
+        return Vec::new();
+    }
+
+    let outputs = res[0].1.len();
+    if outputs == 1 {
+        return vec![res.iter().map(|r| (r.0[0], class(&r.1) == 1)).collect()];
+    }
+    (0..outputs).map(|c| res.iter().map(|r| (r.0[c], class(&r.1) == c)).collect()).collect()
+}
+
+/// Area under ROC curve of binary problem computed by ranks of scores
+fn auc(scores: &mut [(f64, bool)]) -> Option<f64> {
+    let positives = scores.iter().filter(|s| s.1).count() as f64;
+    let negatives = scores.len() as f64 - positives;
+    if positives == 0.0 || negatives == 0.0 {
+        return None;
+    }
+
+    scores.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    // sum of ranks of positive elements, tied scores get average rank
+    let mut ranks = 0.0;
+    let mut i = 0;
+    while i < scores.len() {
+        let mut j = i;
+        while j < scores.len() && scores[j].0 == scores[i].0 {
+            j += 1;
+        }
+        let rank = (i + j + 1) as f64 / 2.0;
+        ranks += rank * scores[i..j].iter().filter(|s| s.1).count() as f64;
+        i = j;
+    }
+
+    Some((ranks - positives * (positives + 1.0) / 2.0) / (positives * negatives))
+}
+
+/// Average precision of binary problem, i.e. area under precision-recall curve
+fn average_precision(scores: &mut [(f64, bool)]) -> Option<f64> {
+    let positives = scores.iter().filter(|s| s.1).count() as f64;
+    if positives == 0.0 {
+        return None;
+    }
+
+    scores.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let (mut tp, mut area) = (0.0, 0.0);
+    let mut i = 0;
+    while i < scores.len() {
+        let mut j = i;
+        let mut new_tp = 0.0;
+        while j < scores.len() && scores[j].0 == scores[i].0 {
+            if scores[j].1 {
+                new_tp += 1.0;
+            }
+            j += 1;
+        }
+        tp += new_tp;
+        area += (new_tp / positives) * (tp / j as f64);
+        i = j;
+    }
+    Some(area)
+}
+
+/// Average metric over classes where it is defined. Elements with `NaN` score are skipped.
+fn macro_average<F>(classes: Vec<Vec<(f64, bool)>>, f: F) -> f64 where F: Fn(&mut [(f64, bool)]) -> Option<f64>{
+    let values: Vec<f64> = classes.into_iter().filter_map(|mut c| {
+        c.retain(|s| !s.0.is_nan());
+        f(&mut c)
+    }).collect();
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Area under ROC curve. For several outputs it is calculated for each class
+/// in one-vs-rest manner and averaged over classes which are present in data.
+/// Elements with `NaN` output, e.g. of diverged network, are skipped.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> f64` - ROC-AUC, `NaN` if data does not contain both positive
+///   and negative elements.
+pub fn roc_auc<T>(nn: &mut FeedForward, data: &T) -> f64 where T: Extractable{
+    macro_average(binary(nn, data), auc)
+}
+
+/// Area under precision-recall curve computed as average precision. For several
+/// outputs it is calculated for each class in one-vs-rest manner and averaged
+/// over classes which are present in data. Elements with `NaN` output are skipped.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> f64` - PR-AUC, `NaN` if data does not contain positive elements.
+pub fn pr_auc<T>(nn: &mut FeedForward, data: &T) -> f64 where T: Extractable{
+    macro_average(binary(nn, data), average_precision)
+}
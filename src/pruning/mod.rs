@@ -0,0 +1,210 @@
+//! Module contains functions for pruning of `FeedForward` neural networks.
+//!
+//! Weights may be pruned by their magnitude. Pruned weights are set to zero and
+//! masked, so they are not changed while further training. Whole neurons may be
+//! removed according to their sensitivity, i.e. the increase of error on data
+//! when neuron is switched off.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::FeedForward;
+//! use neuroflow::data::DataSet;
+//! use neuroflow::pruning;
+//!
+//! let mut nn = FeedForward::new(&[2, 8, 1]);
+//! let mut data = DataSet::new();
+//! data.push(&[0.0, 1.0], &[1.0]);
+//! data.push(&[1.0, 0.0], &[1.0]);
+//!
+//! nn.train(&data, 1_000);
+//!
+//! /* Five times prune 20% of weights and retrain network */
+//! let errors = pruning::iterative(&mut nn, &data, 5, 0.2, 1_000);
+//! println!("sparsity: {:.2}", pruning::sparsity(&nn));
+//!
+//! /* Remove 2 neurons which are the least important */
+//! pruning::prune_neurons(&mut nn, &data, 2);
+//! ```
+
+use data::Extractable;
+use metrics::mse;
+use FeedForward;
+
+/// Sensitivity of network to removal of hidden neuron.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sensitivity {
+    /// Index of layer. Layer indexing starts from 1
+    pub layer: usize,
+    /// Index of neuron in layer
+    pub neuron: usize,
+    /// Increase of mean squared error on data when neuron is switched off
+    pub score: f64,
+}
+
+/// Create mask of layer if it does not exist yet
+fn ensure_mask(nn: &mut FeedForward, j: usize){
+    let layer = &mut nn.layers[j];
+    if layer.mask.is_empty() {
+        layer.mask = layer.w.iter().map(|w| vec![true; w.len()]).collect();
+    }
+}
+
+/// Prune given share of the smallest by absolute value weights among weights
+/// of selected layers. Biases are never pruned, `NaN` weights are pruned last.
+fn prune(nn: &mut FeedForward, layers: &[usize], amount: f64){
+    let mut weights: Vec<(f64, usize, usize, usize)> = Vec::new();
+    for &j in layers{
+        for i in 0..nn.layers[j].w.len(){
+            for k in 1..nn.layers[j].w[i].len(){
+                if !nn.layers[j].is_pruned(i, k) {
+                    weights.push((nn.layers[j].w[i][k].abs(), j, i, k));
+                }
+            }
+        }
+    }
+    weights.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let count = (amount * weights.len() as f64) as usize;
+    for &(_, j, i, k) in weights.iter().take(count){
+        ensure_mask(nn, j);
+        nn.layers[j].w[i][k] = 0.0;
+        nn.layers[j].mask[i][k] = false;
+    }
+}
+
+/// Prune share of the smallest by absolute value weights over the whole network.
+/// Already pruned weights and biases are not taken into account.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `amount: f64` - share of remaining weights to be pruned, from `0` to `1`.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::pruning;
+///
+/// let mut nn = FeedForward::new(&[4, 5, 2]);
+/// pruning::magnitude(&mut nn, 0.5);
+/// assert_eq!(pruning::sparsity(&nn), 0.5);
+/// ```
+pub fn magnitude(nn: &mut FeedForward, amount: f64){
+    let layers: Vec<usize> = (0..nn.layers.len()).collect();
+    prune(nn, &layers, amount);
+}
+
+/// Prune share of the smallest by absolute value weights of single layer.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `layer: usize` - index of layer. NOTE, layer indexing starts from 1!
+/// * `amount: f64` - share of remaining weights of layer to be pruned, from `0` to `1`.
+pub fn magnitude_layer(nn: &mut FeedForward, layer: usize, amount: f64){
+    prune(nn, &[layer - 1], amount);
+}
+
+/// Share of pruned weights in network. Biases are not taken into account.
+///
+/// * `nn: &FeedForward` - neural network;
+/// * `return -> f64` - share of pruned weights.
+pub fn sparsity(nn: &FeedForward) -> f64{
+    let (mut pruned, mut total) = (0, 0);
+    for layer in nn.layers.iter(){
+        for i in 0..layer.w.len(){
+            for k in 1..layer.w[i].len(){
+                total += 1;
+                if layer.is_pruned(i, k) {
+                    pruned += 1;
+                }
+            }
+        }
+    }
+    pruned as f64 / total as f64
+}
+
+/// Remove masks of network, so pruned weights become trainable again.
+///
+/// * `nn: &mut FeedForward` - neural network.
+pub fn clear(nn: &mut FeedForward){
+    for layer in nn.layers.iter_mut(){
+        layer.mask = Vec::new();
+    }
+}
+
+/// Calculate sensitivity of network to removal of each hidden neuron. Neuron
+/// is switched off by zeroing of its outgoing weights.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `return -> Vec<Sensitivity>` - sensitivities sorted from the least to the most
+///   important neuron. Score is `NaN` for empty data or if network outputs `NaN`,
+///   such neurons are placed last.
+pub fn sensitivity<T>(nn: &mut FeedForward, data: &T) -> Vec<Sensitivity> where T: Extractable{
+    let base = mse(nn, data);
+    let mut res = Vec::new();
+
+    for j in 0..nn.layers.len() - 1{
+        for i in 0..nn.layers[j].y.len(){
+            let saved: Vec<f64> = nn.layers[j + 1].w.iter().map(|w| w[i + 1]).collect();
+            for w in nn.layers[j + 1].w.iter_mut(){
+                w[i + 1] = 0.0;
+            }
+
+            res.push(Sensitivity {layer: j + 1, neuron: i, score: mse(nn, data) - base});
+
+            for (w, v) in nn.layers[j + 1].w.iter_mut().zip(saved.iter()){
+                w[i + 1] = *v;
+            }
+        }
+    }
+
+    res.sort_by(|a, b| a.score.is_nan().cmp(&b.score.is_nan()).then(a.score.total_cmp(&b.score)));
+    res
+}
+
+/// Remove the given amount of the least important hidden neurons one by one.
+/// Sensitivities are recalculated after each removal. Layers always keep at
+/// least one neuron and neurons with `NaN` score are never removed, so nothing
+/// is removed for empty data.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `count: usize` - amount of neurons to be removed;
+/// * `return -> Vec<Sensitivity>` - removed neurons in order of removal.
+pub fn prune_neurons<T>(nn: &mut FeedForward, data: &T, count: usize) -> Vec<Sensitivity> where T: Extractable{
+    let mut removed = Vec::new();
+
+    for _ in 0..count{
+        let candidate = sensitivity(nn, data).into_iter()
+            .find(|s| !s.score.is_nan() && nn.layers[s.layer - 1].y.len() > 1);
+
+        match candidate {
+            Some(s) => {
+                nn.unbind(s.layer, s.neuron);
+                removed.push(s);
+            }
+            None => break
+        }
+    }
+    removed
+}
+
+/// Iteratively prune share of weights by magnitude and retrain network
+/// in order to recover its accuracy.
+///
+/// * `nn: &mut FeedForward` - neural network;
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `steps: usize` - amount of prune-and-retrain steps;
+/// * `amount: f64` - share of remaining weights pruned on each step;
+/// * `iterations: i64` - amount of training iterations after each pruning;
+/// * `return -> Vec<f64>` - mean squared error on data after each step.
+pub fn iterative<T>(nn: &mut FeedForward, data: &T, steps: usize, amount: f64, iterations: i64) -> Vec<f64>
+    where T: Extractable{
+    let mut errors = Vec::new();
+    for _ in 0..steps{
+        magnitude(nn, amount);
+        nn.train(data, iterations);
+        errors.push(mse(nn, data));
+    }
+    errors
+}
@@ -0,0 +1,255 @@
+//! Module contains post-training quantization of `FeedForward` neural networks.
+//!
+//! Quantized network is inference-only. Weights of each layer are stored as
+//! `i8` numbers with a single symmetric scale. Inputs of each layer are
+//! quantized to `i8` with scale and zero-point calibrated on data, biases
+//! are stored as `i32` numbers. Induced fields are accumulated in integers
+//! and only activation functions are calculated in floating point.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::FeedForward;
+//! use neuroflow::data::DataSet;
+//! use neuroflow::quantization::Quantized;
+//!
+//! let mut nn = FeedForward::new(&[2, 4, 1]);
+//! let mut data = DataSet::new();
+//! data.push(&[0.0, 1.0], &[1.0]);
+//! data.push(&[1.0, 0.0], &[1.0]);
+//! nn.train(&data, 1_000);
+//!
+//! let mut q = Quantized::new(&mut nn, &data);
+//! let report = q.compare(&mut nn, &data);
+//! println!("accuracy loss: {:.5}", report.loss());
+//!
+//! let res = q.calc(&[0.0, 1.0])[0];
+//! ```
+
+use data::Extractable;
+use activators;
+
+use ActivationContainer;
+use FeedForward;
+use Transform;
+use ErrorKind;
+
+/// Single quantized layer
+#[derive(Serialize, Deserialize)]
+struct QuantizedLayer {
+    inputs: usize,
+    outputs: usize,
+
+    /// Weights in row-major order, `outputs x inputs`
+    w: Vec<i8>,
+    /// Biases quantized with scale `w_scale * x_scale`
+    b: Vec<i32>,
+
+    w_scale: f64,
+    x_scale: f64,
+    x_zero: i8,
+
+    /// Parameters of activation function which are kept unquantized
+    params: Vec<f64>,
+}
+
+/// Comparison of quantized network with the original one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// Mean squared error of the original network on data
+    pub original: f64,
+    /// Mean squared error of the quantized network on data
+    pub quantized: f64,
+    /// Mean squared difference between outputs of networks
+    pub deviation: f64,
+    /// Maximal absolute difference between outputs of networks
+    pub max_deviation: f64,
+}
+
+impl Report {
+    /// Increase of mean squared error caused by quantization.
+    pub fn loss(&self) -> f64 {
+        self.quantized - self.original
+    }
+}
+
+/// Int8 quantized `FeedForward` network for inference.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::data::DataSet;
+/// use neuroflow::quantization::Quantized;
+/// use neuroflow::io;
+///
+/// let mut nn = FeedForward::new(&[2, 4, 1]);
+/// let mut data = DataSet::new();
+/// data.push(&[0.5, 1.0], &[1.0]);
+///
+/// let mut q = Quantized::new(&mut nn, &data);
+/// io::save(&mut q, "quantized.flow").unwrap();
+///
+/// let mut restored: Quantized = io::load("quantized.flow").unwrap();
+/// # std::fs::remove_file("quantized.flow").unwrap();
+/// assert_eq!(restored.calc(&[0.5, 1.0]), q.calc(&[0.5, 1.0]));
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct Quantized {
+    layers: Vec<QuantizedLayer>,
+    act_type: activators::Type,
+
+    #[serde(skip_deserializing, skip_serializing)]
+    act: ActivationContainer,
+
+    #[serde(skip_deserializing, skip_serializing)]
+    y: Vec<f64>,
+}
+
+/// Scale and zero-point mapping range `[min, max]` to `i8`
+fn calibrate(min: f64, max: f64) -> (f64, i8) {
+    let (min, max) = (min.min(0.0), max.max(0.0));
+    if max - min == 0.0 {
+        return (1.0, 0);
+    }
+
+    let scale = (max - min) / 255.0;
+    let zero = (i8::MIN as f64 - min / scale).round();
+    (scale, zero.max(i8::MIN as f64).min(i8::MAX as f64) as i8)
+}
+
+/// Quantize value by scale and zero-point
+fn quantize(x: f64, scale: f64, zero: i8) -> i8 {
+    let q = (x / scale).round() + zero as f64;
+    q.max(i8::MIN as f64).min(i8::MAX as f64) as i8
+}
+
+impl Quantized {
+    /// Convert trained network to quantized one. Ranges of inputs of each
+    /// layer are calibrated by passing input vectors of data through network.
+    ///
+    /// * `nn: &mut FeedForward` - trained neural network;
+    /// * `data: &T` - calibration data that implements `neuroflow::data::Extractable` trait;
+    /// * `return` - `Quantized` struct.
+    pub fn new<T>(nn: &mut FeedForward, data: &T) -> Quantized where T: Extractable{
+        let count = nn.layers.len();
+        let mut min = vec![f64::MAX; count];
+        let mut max = vec![f64::MIN; count];
+
+        for i in 0..data.len(){
+            let x = data.get(i).0;
+            nn.calc(x);
+
+            for j in 0..count{
+                let input: &[f64] = if j == 0 {x} else {&nn.layers[j - 1].y};
+                for v in input.iter(){
+                    min[j] = min[j].min(*v);
+                    max[j] = max[j].max(*v);
+                }
+            }
+        }
+
+        let mut layers = Vec::new();
+        for j in 0..count{
+            let layer = &nn.layers[j];
+            let inputs = layer.w[0].len() - 1;
+
+            let mut w_max: f64 = 0.0;
+            for w in layer.w.iter(){
+                for v in w[1..].iter(){
+                    w_max = w_max.max(v.abs());
+                }
+            }
+            let w_scale = if w_max == 0.0 {1.0} else {w_max / i8::MAX as f64};
+            let (x_scale, x_zero) = calibrate(min[j], max[j]);
+
+            let mut w = Vec::with_capacity(layer.w.len() * inputs);
+            let mut b = Vec::with_capacity(layer.w.len());
+            for row in layer.w.iter(){
+                for v in row[1..].iter(){
+                    w.push(quantize(*v, w_scale, 0));
+                }
+                b.push((row[0] / (w_scale * x_scale)).round() as i32);
+            }
+
+            layers.push(QuantizedLayer {inputs, outputs: layer.w.len(), w, b, w_scale, x_scale, x_zero,
+                params: layer.params.clone()});
+        }
+
+        Quantized {layers, act_type: nn.act_type.clone(), act: nn.act.clone(), y: Vec::new()}
+    }
+
+    /// Calculate the response by quantized network.
+    ///
+    /// * `X: &[f64]` - slice of input data;
+    /// * `return -> &[f64]` - output of network.
+    #[allow(non_snake_case)]
+    pub fn calc(&mut self, X: &[f64]) -> &[f64]{
+        let mut x = X.to_vec();
+        let mut q = Vec::new();
+
+        for j in 0..self.layers.len(){
+            let layer = &self.layers[j];
+            // like in `FeedForward` the first layer is always activated
+            let linear = j > 0 && j == self.layers.len() - 1;
+
+            q.clear();
+            q.extend(x.iter().map(|v| quantize(*v, layer.x_scale, layer.x_zero) as i32 - layer.x_zero as i32));
+
+            let scale = layer.w_scale * layer.x_scale;
+            x = (0..layer.outputs).map(|i| {
+                let row = &layer.w[i * layer.inputs..(i + 1) * layer.inputs];
+                let mut sum = layer.b[i];
+                for k in 0..layer.inputs{
+                    sum += row[k] as i32 * q[k];
+                }
+
+                let v = sum as f64 * scale;
+                match self.act.param {
+                    _ if linear => v,
+                    Some(ref p) if !layer.params.is_empty() => (p.func)(v, layer.params[i]),
+                    _ => self.act.func.value(v)
+                }
+            }).collect();
+        }
+
+        self.y = x;
+        &self.y
+    }
+
+    /// Compare quantized network with the original one on data.
+    ///
+    /// * `nn: &mut FeedForward` - the original network;
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `return -> Report` - errors of both networks and deviation between them.
+    pub fn compare<T>(&mut self, nn: &mut FeedForward, data: &T) -> Report where T: Extractable{
+        let mut report = Report {original: 0.0, quantized: 0.0, deviation: 0.0, max_deviation: 0.0};
+
+        for i in 0..data.len(){
+            let (x, d) = data.get(i);
+            let y = nn.calc(x).to_vec();
+            let q = self.calc(x);
+
+            for k in 0..d.len(){
+                let n = (d.len() * data.len()) as f64;
+                report.original += (d[k] - y[k]).powi(2) / n;
+                report.quantized += (d[k] - q[k]).powi(2) / n;
+                report.deviation += (y[k] - q[k]).powi(2) / n;
+                report.max_deviation = report.max_deviation.max((y[k] - q[k]).abs());
+            }
+        }
+        report
+    }
+
+    /// Size of quantized weights and biases in bytes.
+    pub fn size(&self) -> usize {
+        self.layers.iter().map(|l| l.w.len() + 4 * l.b.len()).sum()
+    }
+}
+
+impl Transform for Quantized{
+    fn after(&mut self) -> Result<(), ErrorKind>{
+        self.act = ActivationContainer::restore(&self.act_type)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,338 @@
+//! Module contains radial basis function (RBF) neural network.
+//!
+//! RBF network has single hidden layer of Gaussian units
+//! `exp(-|x - c|^2 / (2σ^2))` and linear output layer. Centers `c` are found
+//! by k-means clustering of input vectors, widths `σ` are estimated from the
+//! distances between centers. Output layer is solved by least squares
+//! or trained by gradient descent.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::rbf::RadialBasis;
+//! use neuroflow::data::DataSet;
+//!
+//! let mut data = DataSet::new();
+//! let mut i = -3.0;
+//! while i <= 3.0 {
+//!     data.push(&[i], &[i.sin()]);
+//!     i += 0.1;
+//! }
+//!
+//! /* Network with 10 Gaussian units */
+//! let mut nn = RadialBasis::new(&data, 10);
+//! nn.solve(&data, 1e-6).unwrap();
+//!
+//! let res = nn.calc(&[0.5])[0];
+//! ```
+
+use rand::{thread_rng, Rng};
+use rand::distributions::Uniform;
+
+use math;
+use data::Extractable;
+
+use ErrorKind;
+use Transform;
+
+/// Find centers of `k` clusters of input vectors of data by k-means algorithm.
+/// Initial centers are chosen by k-means++ procedure.
+///
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `k: usize` - amount of clusters;
+/// * `iterations: usize` - maximal amount of iterations;
+/// * `return -> Vec<Vec<f64>>` - centers of clusters.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::rbf::kmeans;
+/// use neuroflow::data::DataSet;
+///
+/// let mut data = DataSet::new();
+/// data.push(&[0.0, 0.1], &[]);
+/// data.push(&[0.1, 0.0], &[]);
+/// data.push(&[5.0, 5.1], &[]);
+/// data.push(&[5.1, 5.0], &[]);
+///
+/// let centers = kmeans(&data, 2, 100);
+/// ```
+#[allow(clippy::needless_range_loop)]
+pub fn kmeans<T>(data: &T, k: usize, iterations: usize) -> Vec<Vec<f64>> where T: Extractable{
+    assert!(k > 0 && k <= data.len(), "amount of clusters must be in range [1, data.len()]");
+
+    let mut rng = thread_rng();
+    let mut centers = vec![data.get(rng.sample(Uniform::new(0, data.len()))).0.clone()];
+
+    // k-means++: next center is chosen with probability proportional to
+    // the squared distance to the nearest existing center
+    while centers.len() < k {
+        let d: Vec<f64> = (0..data.len()).map(|i| nearest(&centers, data.get(i).0).1).collect();
+        let total: f64 = d.iter().sum();
+        if total == 0.0 {
+            centers.push(data.get(rng.sample(Uniform::new(0, data.len()))).0.clone());
+            continue;
+        }
+
+        let mut r = rng.gen::<f64>() * total;
+        let mut chosen = d.len() - 1;
+        for i in 0..d.len(){
+            r -= d[i];
+            if r <= 0.0 {
+                chosen = i;
+                break;
+            }
+        }
+        centers.push(data.get(chosen).0.clone());
+    }
+
+    let mut labels = vec![usize::MAX; data.len()];
+    for _ in 0..iterations{
+        let mut changed = false;
+        for i in 0..data.len(){
+            let (c, _) = nearest(&centers, data.get(i).0);
+            if labels[i] != c {
+                labels[i] = c;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = math::zeros(k, centers[0].len());
+        let mut counts = vec![0usize; k];
+        for i in 0..data.len(){
+            let x = data.get(i).0;
+            counts[labels[i]] += 1;
+            for j in 0..x.len(){
+                sums[labels[i]][j] += x[j];
+            }
+        }
+        for c in 0..k{
+            if counts[c] > 0 {
+                centers[c] = sums[c].iter().map(|v| v / counts[c] as f64).collect();
+            }
+        }
+    }
+
+    centers
+}
+
+/// Index of the nearest center and squared distance to it
+fn nearest(centers: &[Vec<f64>], x: &[f64]) -> (usize, f64) {
+    let mut best = (0, f64::MAX);
+    for (i, c) in centers.iter().enumerate(){
+        let d = math::distance2(c, x);
+        if d < best.1 {
+            best = (i, d);
+        }
+    }
+    best
+}
+
+/// Radial basis function neural network.
+///
+/// # Examples
+///
+/// Create network from given centers and widths:
+///
+/// ```rust
+/// use neuroflow::rbf::RadialBasis;
+///
+/// let mut nn = RadialBasis::with_centers(&[vec![0.0], vec![1.0]], &[0.5, 0.5], 1);
+///
+/// nn.learning_rate(0.1);
+/// for _ in 0..1_000{
+///     nn.fit(&[0.0], &[1.0]);
+///     nn.fit(&[1.0], &[-1.0]);
+/// }
+/// let res = nn.calc(&[0.0])[0];
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct RadialBasis {
+    centers: Vec<Vec<f64>>,
+    widths: Vec<f64>,
+    w: Vec<Vec<f64>>,
+
+    learn_rate: f64,
+    error: f64,
+
+    #[serde(skip_deserializing, skip_serializing)]
+    y: Vec<f64>,
+}
+
+impl RadialBasis {
+    /// The constructor of `RadialBasis` struct. Centers are found by k-means
+    /// clustering of input vectors of data. Width of each unit is the distance
+    /// to the nearest other center.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait.
+    ///   Amount of outputs is equal to the length of its expected output vectors;
+    /// * `centers: usize` - amount of Gaussian units;
+    /// * `return` - `RadialBasis` struct.
+    pub fn new<T>(data: &T, centers: usize) -> RadialBasis where T: Extractable{
+        let c = kmeans(data, centers, 100);
+        let mut widths = vec![1.0; c.len()];
+
+        for i in 0..c.len(){
+            let mut min = f64::MAX;
+            for j in 0..c.len(){
+                let d = math::distance2(&c[i], &c[j]);
+                if i != j && d > 0.0 && d < min {
+                    min = d;
+                }
+            }
+            if min < f64::MAX {
+                widths[i] = min.sqrt();
+            }
+        }
+
+        RadialBasis::with_centers(&c, &widths, data.get(0).1.len())
+    }
+
+    /// Create network with given centers and widths of Gaussian units.
+    ///
+    /// * `centers: &[Vec<f64>]` - centers of units;
+    /// * `widths: &[f64]` - widths `σ` of units;
+    /// * `outputs: usize` - amount of outputs;
+    /// * `return` - `RadialBasis` struct.
+    pub fn with_centers(centers: &[Vec<f64>], widths: &[f64], outputs: usize) -> RadialBasis {
+        assert_eq!(centers.len(), widths.len(), "each center must have its width");
+
+        RadialBasis {
+            centers: centers.to_vec(),
+            widths: widths.to_vec(),
+            w: math::random(outputs, centers.len() + 1, 0.1),
+            learn_rate: 0.1,
+            error: 0.0,
+            y: Vec::new(),
+        }
+    }
+
+    /// Outputs of Gaussian units with leading `1` for bias
+    fn hidden(&self, x: &[f64]) -> Vec<f64> {
+        let mut phi = vec![1.0];
+        for i in 0..self.centers.len(){
+            let d = math::distance2(&self.centers[i], x);
+            phi.push((-d / (2.0 * self.widths[i].powi(2))).exp());
+        }
+        phi
+    }
+
+    /// Solve output layer by regularized least squares over the whole data.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `regularization: f64` - ridge regularization coefficient. Small positive value
+    ///   makes solution stable;
+    /// * `return -> Result<(), ErrorKind>` - `ErrorKind::Singular` if system cannot be solved.
+    #[allow(clippy::needless_range_loop)]
+    pub fn solve<T>(&mut self, data: &T, regularization: f64) -> Result<(), ErrorKind> where T: Extractable{
+        let n = self.centers.len() + 1;
+        let outputs = self.w.len();
+        let mut a = math::zeros(n, n);
+        let mut b = math::zeros(n, outputs);
+
+        for s in 0..data.len(){
+            let (x, d) = data.get(s);
+            let phi = self.hidden(x);
+            for i in 0..n{
+                for j in 0..n{
+                    a[i][j] += phi[i] * phi[j];
+                }
+                for k in 0..outputs{
+                    b[i][k] += phi[i] * d[k];
+                }
+            }
+        }
+        for i in 0..n{
+            a[i][i] += regularization;
+        }
+
+        let x = math::solve(a, b).ok_or(ErrorKind::Singular)?;
+        for k in 0..outputs{
+            for i in 0..n{
+                self.w[k][i] = x[i][k];
+            }
+        }
+        Ok(())
+    }
+
+    /// Train output layer by gradient descent over bulked data.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `iterations: i64` - iterations count.
+    pub fn train<T>(&mut self, data: &T, iterations: i64) where T: Extractable{
+        for _ in 0..iterations{
+            let (x, y) = data.rand();
+            self.fit(x, y);
+        }
+    }
+
+    /// Train output layer by gradient descent step by step.
+    ///
+    /// * `x: &[f64]` - slice of input data;
+    /// * `d: &[f64]` - expected output.
+    #[allow(clippy::needless_range_loop)]
+    pub fn fit(&mut self, x: &[f64], d: &[f64]){
+        let phi = self.hidden(x);
+
+        self.error = 0.0;
+        for k in 0..self.w.len(){
+            let e = d[k] - math::dot(&self.w[k], &phi);
+            self.error += 0.5 * e * e;
+            for i in 0..phi.len(){
+                self.w[k][i] += self.learn_rate * e * phi[i];
+            }
+        }
+    }
+
+    /// Calculate the response by network.
+    ///
+    /// * `x: &[f64]` - slice of input data;
+    /// * `return -> &[f64]` - slice of calculated data.
+    pub fn calc(&mut self, x: &[f64]) -> &[f64]{
+        let phi = self.hidden(x);
+        self.y = math::mul(&self.w, &phi);
+        &self.y
+    }
+
+    /// Set the same width for all Gaussian units.
+    ///
+    /// * `width: f64` - width `σ`;
+    /// * `return -> &mut RadialBasis` - link on the current struct.
+    pub fn width(&mut self, width: f64) -> &mut RadialBasis {
+        for w in self.widths.iter_mut(){
+            *w = width;
+        }
+        self
+    }
+
+    /// Set the learning rate of gradient descent.
+    ///
+    /// * `learning_rate: f64` - learning rate;
+    /// * `return -> &mut RadialBasis` - link on the current struct.
+    pub fn learning_rate(&mut self, learning_rate: f64) -> &mut RadialBasis {
+        self.learn_rate = learning_rate;
+        self
+    }
+
+    /// Get centers of Gaussian units
+    pub fn centers(&self) -> &[Vec<f64>] {
+        &self.centers
+    }
+
+    /// Get widths of Gaussian units
+    pub fn widths(&self) -> &[f64] {
+        &self.widths
+    }
+
+    /// Get current training error
+    ///
+    /// * `return -> f64` - training error
+    pub fn get_error(&self) -> f64 {
+        self.error
+    }
+}
+
+impl Transform for RadialBasis{}
@@ -0,0 +1,642 @@
+//! Module contains recurrent neural networks that are trained by
+//! truncated backpropagation through time (BPTT).
+//!
+//! The most valuable unit of this module is `Recurrent` struct. Its hidden
+//! layers may be built from simple (Elman) cells, LSTM cells or GRU cells.
+//! Sequences for training are stored in `neuroflow::data::SequenceSet`.
+//!
+//! # Example
+//!
+//! Forecasting of the next value of time series:
+//!
+//! ```rust
+//! use neuroflow::recurrent::{Recurrent, Cell};
+//! use neuroflow::data::SequenceSet;
+//!
+//! let series: Vec<f64> = (0..100).map(|i| (i as f64 * 0.2).sin()).collect();
+//! let data = SequenceSet::from_series(&series, 10, 1);
+//!
+//! let mut nn = Recurrent::new(Cell::Lstm, &[1, 8, 1]);
+//! nn.learning_rate(0.05)
+//!     .bptt(5)
+//!     .train(&data, 1_000);
+//!
+//! let forecast = nn.calc(&[vec![0.0], vec![0.2], vec![0.39]]);
+//! println!("{:?}", forecast.last());
+//! ```
+
+use activators;
+use data::SequenceExtractable;
+use math;
+
+use ActivationContainer;
+use Transform;
+use ErrorKind;
+
+/// Determine types of cells of recurrent layers.
+///
+/// * `Elman` - simple recurrent cell `h = f(W·[1, x, h'])` where `f` is
+///   activation function of network;
+/// * `Lstm` - long short-term memory cell with input, forget and output gates;
+/// * `Gru` - gated recurrent unit with update and reset gates.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Cell {
+    Elman,
+    Lstm,
+    Gru
+}
+
+impl Cell {
+    /// Amount of weight matrices in single layer of cells
+    fn gates(&self) -> usize {
+        match *self {
+            Cell::Elman => 1,
+            Cell::Lstm => 4,
+            Cell::Gru => 3,
+        }
+    }
+}
+
+/// Struct `RecurrentLayer` represents single recurrent layer.
+/// Each gate has its own weight matrix which is applied to vector `[1, x, h']`,
+/// where `x` is the input of layer and `h'` is the hidden state from previous step.
+///
+/// Order of gates is `[input, forget, output, candidate]` for LSTM
+/// and `[update, reset, candidate]` for GRU.
+#[derive(Serialize, Deserialize)]
+struct RecurrentLayer {
+    input: usize,
+    hidden: usize,
+    w: Vec<Vec<Vec<f64>>>,
+
+    #[serde(skip_deserializing, skip_serializing)]
+    velocity: Vec<Vec<Vec<f64>>>,
+}
+
+type Matrix = Vec<Vec<f64>>;
+
+/// Values of single layer cached on the forward pass of one time step
+struct Step {
+    xh: Vec<f64>,
+    xrh: Vec<f64>,
+    v: Vec<f64>,
+    gates: Vec<Vec<f64>>,
+    h_prev: Vec<f64>,
+    c_prev: Vec<f64>,
+    c: Vec<f64>,
+    h: Vec<f64>,
+}
+
+/// Recurrent neural network that is trained by truncated backpropagation
+/// through time. Hidden layers are built from cells of the same `Cell` type
+/// and the output layer is linear.
+///
+/// # Examples
+///
+/// In order to create `Recurrent` instance call its constructor `new`.
+/// The architecture slice has the same meaning as for `FeedForward` network:
+/// the first element is amount of inputs at each time step, the last one is
+/// amount of outputs and the elements between are the sizes of stacked
+/// recurrent layers.
+///
+/// ```rust
+/// use neuroflow::recurrent::{Recurrent, Cell};
+///
+/// let mut nn = Recurrent::new(Cell::Gru, &[2, 6, 1]);
+/// ```
+///
+/// Train the network by single sequence via `fit` method or by
+/// `neuroflow::data::SequenceSet` via `train` method:
+///
+/// ```rust
+/// # use neuroflow::recurrent::{Recurrent, Cell};
+/// # let mut nn = Recurrent::new(Cell::Gru, &[2, 6, 1]);
+/// use neuroflow::data::SequenceSet;
+///
+/// nn.fit(&[vec![0.1, 0.2], vec![0.3, 0.1]], &[vec![0.4]]);
+///
+/// let mut data = SequenceSet::new();
+/// data.push(&[vec![0.1, 0.2], vec![0.3, 0.1]], &[vec![0.4]]);
+/// nn.train(&data, 1_000);
+/// ```
+///
+/// Call `calc` method in order to get outputs for each step of sequence:
+///
+/// ```rust
+/// # use neuroflow::recurrent::{Recurrent, Cell};
+/// # let mut nn = Recurrent::new(Cell::Gru, &[2, 6, 1]);
+/// let res: Vec<Vec<f64>> = nn.calc(&[vec![0.1, 0.2], vec![0.3, 0.1]]);
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct Recurrent {
+    cell: Cell,
+    layers: Vec<RecurrentLayer>,
+    out: Vec<Vec<f64>>,
+
+    learn_rate: f64,
+    momentum: f64,
+    error: f64,
+    bptt: usize,
+    clip: f64,
+
+    act_type: activators::Type,
+
+    #[serde(skip_deserializing, skip_serializing)]
+    act: ActivationContainer,
+
+    #[serde(skip_deserializing, skip_serializing)]
+    out_velocity: Vec<Vec<f64>>,
+
+    #[serde(skip_deserializing, skip_serializing)]
+    state: Vec<(Vec<f64>, Vec<f64>)>,
+}
+
+impl RecurrentLayer {
+    fn new(cell: Cell, hidden: usize, input: usize) -> RecurrentLayer {
+        let cols = 1 + input + hidden;
+        let scale = 1.0 / (cols as f64).sqrt();
+        let mut w = Vec::new();
+
+        for _ in 0..cell.gates(){
+            w.push(math::random(hidden, cols, scale));
+        }
+
+        // Forget gate is biased to remember on start of training
+        if cell == Cell::Lstm {
+            for row in w[1].iter_mut(){
+                row[0] = 1.0;
+            }
+        }
+
+        RecurrentLayer {input, hidden, w, velocity: Vec::new()}
+    }
+}
+
+impl Recurrent {
+    /// The constructor of `Recurrent` struct
+    ///
+    /// * `cell: Cell` - type of cells of recurrent layers;
+    /// * `architecture: &[i32]` - the architecture of network where the first element
+    ///   is amount of inputs at each time step, the last one is amount of outputs,
+    ///   and each element between them is amount of cells in recurrent layer;
+    /// * `return` - `Recurrent` struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neuroflow::recurrent::{Recurrent, Cell};
+    /// let mut nn = Recurrent::new(Cell::Elman, &[1, 5, 5, 1]);
+    /// ```
+    pub fn new(cell: Cell, architecture: &[i32]) -> Recurrent {
+        assert!(architecture.len() >= 3, "recurrent network must have at least one hidden layer");
+
+        let mut nn = Recurrent {cell, layers: Vec::new(), out: Vec::new(),
+            learn_rate: 0.01, momentum: 0.0, error: 0.0, bptt: 20, clip: 5.0,
+            act_type: activators::Type::Tanh,
+            act: ActivationContainer::from_type(&activators::Type::Tanh),
+            out_velocity: Vec::new(),
+            state: Vec::new()};
+
+        for i in 1..architecture.len() - 1 {
+            nn.layers.push(RecurrentLayer::new(cell, architecture[i] as usize, architecture[i - 1] as usize));
+        }
+
+        let last = architecture[architecture.len() - 2] as usize;
+        let outputs = architecture[architecture.len() - 1] as usize;
+        nn.out = math::random(outputs, last + 1, 1.0 / ((last + 1) as f64).sqrt());
+
+        nn.reset();
+        nn
+    }
+
+    fn zero_state(&self) -> Vec<(Vec<f64>, Vec<f64>)> {
+        self.layers.iter().map(|l| (vec![0.0; l.hidden], vec![0.0; l.hidden])).collect()
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn forward_step(&self, l: usize, x: &[f64], h_prev: &[f64], c_prev: &[f64]) -> Step {
+        let layer = &self.layers[l];
+        let mut xh = Vec::with_capacity(1 + x.len() + h_prev.len());
+        xh.push(1.0);
+        xh.extend_from_slice(x);
+        xh.extend_from_slice(h_prev);
+
+        let mut step = Step {xh: Vec::new(), xrh: Vec::new(), v: Vec::new(), gates: Vec::new(),
+            h_prev: h_prev.to_vec(), c_prev: c_prev.to_vec(), c: Vec::new(), h: Vec::new()};
+
+        match self.cell {
+            Cell::Elman => {
+                step.v = math::mul(&layer.w[0], &xh);
+                step.h = step.v.iter().map(|v| self.act.func.value(*v)).collect();
+            }
+            Cell::Lstm => {
+                for g in 0..3{
+                    step.gates.push(math::mul(&layer.w[g], &xh).iter().map(|v| math::logistic(*v)).collect());
+                }
+                step.gates.push(math::mul(&layer.w[3], &xh).iter().map(|v| v.tanh()).collect());
+
+                step.c = vec![0.0; layer.hidden];
+                step.h = vec![0.0; layer.hidden];
+                for i in 0..layer.hidden{
+                    step.c[i] = step.gates[1][i] * c_prev[i] + step.gates[0][i] * step.gates[3][i];
+                    step.h[i] = step.gates[2][i] * step.c[i].tanh();
+                }
+            }
+            Cell::Gru => {
+                for g in 0..2{
+                    step.gates.push(math::mul(&layer.w[g], &xh).iter().map(|v| math::logistic(*v)).collect());
+                }
+
+                let mut xrh = xh.clone();
+                for i in 0..layer.hidden{
+                    xrh[1 + layer.input + i] *= step.gates[1][i];
+                }
+                step.gates.push(math::mul(&layer.w[2], &xrh).iter().map(|v| v.tanh()).collect());
+                step.xrh = xrh;
+
+                step.h = vec![0.0; layer.hidden];
+                for i in 0..layer.hidden{
+                    let z = step.gates[0][i];
+                    step.h[i] = (1.0 - z) * step.gates[2][i] + z * h_prev[i];
+                }
+            }
+        }
+
+        step.xh = xh;
+        step
+    }
+
+    /// Propagate the gradient of error by hidden state `dh` and cell state `dc`
+    /// through the cached step. Gradients of weights are accumulated in `grad`.
+    ///
+    /// * `return` - gradients by input of layer, previous hidden state and
+    ///   previous cell state.
+    #[allow(clippy::needless_range_loop)]
+    fn backward_step(&self, l: usize, step: &Step, dh: &[f64], dc: &[f64],
+                     grad: &mut [Vec<Vec<f64>>]) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        let layer = &self.layers[l];
+        let (n, input) = (layer.hidden, layer.input);
+
+        let mut dxh = vec![0.0; step.xh.len()];
+        let mut dc_prev = vec![0.0; n];
+        let mut extra_dh = vec![0.0; n];
+
+        // Gradient by induced field of each gate
+        let mut da = vec![vec![0.0; n]; self.cell.gates()];
+
+        match self.cell {
+            Cell::Elman => {
+                for i in 0..n{
+                    da[0][i] = dh[i] * self.act.func.derivative_from_output(step.v[i], step.h[i]);
+                }
+            }
+            Cell::Lstm => {
+                let g = &step.gates;
+                for i in 0..n{
+                    let tc = step.c[i].tanh();
+                    let dci = dc[i] + dh[i] * g[2][i] * (1.0 - tc * tc);
+
+                    da[0][i] = dci * g[3][i] * g[0][i] * (1.0 - g[0][i]);
+                    da[1][i] = dci * step.c_prev[i] * g[1][i] * (1.0 - g[1][i]);
+                    da[2][i] = dh[i] * tc * g[2][i] * (1.0 - g[2][i]);
+                    da[3][i] = dci * g[0][i] * (1.0 - g[3][i] * g[3][i]);
+
+                    dc_prev[i] = dci * g[1][i];
+                }
+            }
+            Cell::Gru => {
+                let g = &step.gates;
+                for i in 0..n{
+                    da[2][i] = dh[i] * (1.0 - g[0][i]) * (1.0 - g[2][i] * g[2][i]);
+                    extra_dh[i] = dh[i] * g[0][i];
+                }
+
+                // Candidate is computed from [1, x, r*h'], so its gradient
+                // is spread through reset gate
+                for i in 0..n{
+                    for k in 0..step.xrh.len(){
+                        grad[2][i][k] += da[2][i] * step.xrh[k];
+                    }
+                }
+                let drh = math::mul_transposed(&layer.w[2], &da[2]);
+                for k in 0..1 + input{
+                    dxh[k] += drh[k];
+                }
+
+                for i in 0..n{
+                    let rh = drh[1 + input + i];
+                    extra_dh[i] += rh * g[1][i];
+                    da[1][i] = rh * step.h_prev[i] * g[1][i] * (1.0 - g[1][i]);
+                    da[0][i] = dh[i] * (step.h_prev[i] - g[2][i]) * g[0][i] * (1.0 - g[0][i]);
+                }
+            }
+        }
+
+        let gates = if self.cell == Cell::Gru { 2 } else { self.cell.gates() };
+        for g in 0..gates{
+            for i in 0..n{
+                for k in 0..step.xh.len(){
+                    grad[g][i][k] += da[g][i] * step.xh[k];
+                }
+            }
+            let d = math::mul_transposed(&layer.w[g], &da[g]);
+            for k in 0..d.len(){
+                dxh[k] += d[k];
+            }
+        }
+
+        let dx = dxh[1..1 + input].to_vec();
+        let mut dh_prev = dxh[1 + input..].to_vec();
+        for i in 0..n{
+            dh_prev[i] += extra_dh[i];
+        }
+
+        (dx, dh_prev, dc_prev)
+    }
+
+    fn output(&self, h: &[f64]) -> Vec<f64> {
+        self.out.iter().map(|row| row[0] + math::dot(&row[1..], h)).collect()
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn update(&mut self, grads: &[Vec<Matrix>], out_grad: &Matrix){
+        let (rate, momentum, clip) = (self.learn_rate, self.momentum, self.clip);
+        let step = |w: &mut Matrix, v: &mut Matrix, g: &Matrix| {
+            for i in 0..w.len(){
+                for k in 0..w[i].len(){
+                    let d = f64::max(-clip, f64::min(clip, g[i][k]));
+                    v[i][k] = momentum * v[i][k] - rate * d;
+                    w[i][k] += v[i][k];
+                }
+            }
+        };
+
+        for (l, layer) in self.layers.iter_mut().enumerate(){
+            if layer.velocity.is_empty(){
+                layer.velocity = layer.w.iter().map(|w| math::zeros(w.len(), w[0].len())).collect();
+            }
+            for g in 0..layer.w.len(){
+                step(&mut layer.w[g], &mut layer.velocity[g], &grads[l][g]);
+            }
+        }
+
+        if self.out_velocity.is_empty(){
+            self.out_velocity = math::zeros(self.out.len(), self.out[0].len());
+        }
+        step(&mut self.out, &mut self.out_velocity, out_grad);
+    }
+
+    fn zero_grads(&self) -> (Vec<Vec<Matrix>>, Vec<Vec<f64>>) {
+        let grads = self.layers.iter()
+            .map(|l| l.w.iter().map(|w| math::zeros(w.len(), w[0].len())).collect())
+            .collect();
+        (grads, math::zeros(self.out.len(), self.out[0].len()))
+    }
+
+    /// Train neural network by bulked sequences.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::SequenceExtractable` trait;
+    /// * `iterations: i64` - iterations count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::recurrent::{Recurrent, Cell};
+    /// # let mut nn = Recurrent::new(Cell::Lstm, &[1, 3, 1]);
+    /// let mut d = neuroflow::data::SequenceSet::new();
+    /// d.push(&[vec![1.2], vec![0.3]], &[vec![-0.2]]);
+    /// nn.train(&d, 1_000);
+    /// ```
+    pub fn train<T>(&mut self, data: &T, iterations: i64) where T: SequenceExtractable{
+        for _ in 0..iterations{
+            let (x, y) = data.rand();
+            self.fit(x, y);
+        }
+    }
+
+    /// Train neural network by single sequence. The sequence is divided into
+    /// chunks of `bptt` steps. Error is propagated back only within the chunk
+    /// and weights are updated after each chunk, while hidden state is carried
+    /// through the whole sequence.
+    ///
+    /// * `xs: &[Vec<f64>]` - sequence of input vectors;
+    /// * `ds: &[Vec<f64>]` - sequence of expected output vectors. If it is shorter
+    ///   than `xs` it is aligned to the end of input sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::recurrent::{Recurrent, Cell};
+    /// # let mut nn = Recurrent::new(Cell::Lstm, &[1, 3, 1]);
+    /// nn.fit(&[vec![1.0], vec![0.5], vec![0.25]], &[vec![0.125]]);
+    /// ```
+    pub fn fit(&mut self, xs: &[Vec<f64>], ds: &[Vec<f64>]){
+        assert!(ds.len() <= xs.len(), "expected output sequence is longer than input sequence");
+
+        let offset = xs.len() - ds.len();
+        let chunk = if self.bptt == 0 { xs.len().max(1) } else { self.bptt };
+        let mut state = self.zero_state();
+        let mut start = 0;
+
+        self.error = 0.0;
+        while start < xs.len() {
+            let end = usize::min(start + chunk, xs.len());
+            let mut steps: Vec<Vec<Step>> = Vec::new();
+            let mut outputs: Vec<Vec<f64>> = Vec::new();
+
+            for x in xs[start..end].iter(){
+                let mut layer_steps: Vec<Step> = Vec::new();
+                for l in 0..self.layers.len(){
+                    let s = if l == 0 {
+                        self.forward_step(l, x, &state[l].0, &state[l].1)
+                    } else {
+                        self.forward_step(l, &layer_steps[l - 1].h, &state[l].0, &state[l].1)
+                    };
+                    state[l] = (s.h.clone(), s.c.clone());
+                    layer_steps.push(s);
+                }
+                outputs.push(self.output(&layer_steps.last().unwrap().h));
+                steps.push(layer_steps);
+            }
+
+            let (mut grads, mut out_grad) = self.zero_grads();
+            let mut dh_next: Vec<Vec<f64>> = self.layers.iter().map(|l| vec![0.0; l.hidden]).collect();
+            let mut dc_next = dh_next.clone();
+
+            for t in (start..end).rev(){
+                let layer_steps = &steps[t - start];
+                let top = &layer_steps.last().unwrap().h;
+                let mut dh = vec![0.0; top.len()];
+
+                if t >= offset {
+                    let d = &ds[t - offset];
+                    let y = &outputs[t - start];
+                    for i in 0..y.len(){
+                        let e = y[i] - d[i];
+                        self.error += 0.5 * e * e;
+
+                        out_grad[i][0] += e;
+                        for k in 0..top.len(){
+                            out_grad[i][k + 1] += e * top[k];
+                            dh[k] += e * self.out[i][k + 1];
+                        }
+                    }
+                }
+
+                for l in (0..self.layers.len()).rev(){
+                    for i in 0..dh.len(){
+                        dh[i] += dh_next[l][i];
+                    }
+                    let (dx, dh_prev, dc_prev) = self.backward_step(l, &layer_steps[l], &dh, &dc_next[l], &mut grads[l]);
+                    dh_next[l] = dh_prev;
+                    dc_next[l] = dc_prev;
+                    dh = dx;
+                }
+            }
+
+            self.update(&grads, &out_grad);
+            start = end;
+        }
+    }
+
+    /// Calculate the response of network for each step of sequence. Calculation
+    /// starts from zero hidden state.
+    ///
+    /// * `xs: &[Vec<f64>]` - sequence of input vectors;
+    /// * `return -> Vec<Vec<f64>>` - output vectors for each step of sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::recurrent::{Recurrent, Cell};
+    /// # let mut nn = Recurrent::new(Cell::Lstm, &[1, 3, 1]);
+    /// let res = nn.calc(&[vec![1.0], vec![0.5]]);
+    /// assert_eq!(res.len(), 2);
+    /// ```
+    pub fn calc(&mut self, xs: &[Vec<f64>]) -> Vec<Vec<f64>>{
+        self.reset();
+        xs.iter().map(|x| self.step(x)).collect()
+    }
+
+    /// Calculate the response of network for single time step. Hidden state
+    /// is kept between calls, so the method is useful for step by step
+    /// forecasting. Call `reset` in order to start new sequence.
+    ///
+    /// * `x: &[f64]` - input vector of current step;
+    /// * `return -> Vec<f64>` - output vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use neuroflow::recurrent::{Recurrent, Cell};
+    /// # let mut nn = Recurrent::new(Cell::Gru, &[1, 3, 1]);
+    /// let mut y = nn.step(&[1.0]);
+    /// for _ in 0..5{
+    ///     y = nn.step(&y);
+    /// }
+    /// nn.reset();
+    /// ```
+    pub fn step(&mut self, x: &[f64]) -> Vec<f64>{
+        if self.state.len() != self.layers.len() {
+            self.reset();
+        }
+
+        let mut input = x.to_vec();
+        for l in 0..self.layers.len(){
+            let s = self.forward_step(l, &input, &self.state[l].0, &self.state[l].1);
+            input = s.h.clone();
+            self.state[l] = (s.h, s.c);
+        }
+        self.output(&input)
+    }
+
+    /// Reset hidden state of network to zeros.
+    pub fn reset(&mut self){
+        self.state = self.zero_state();
+    }
+
+    /// Choose activation function of `Cell::Elman` cells. Gated cells always use
+    /// logistic gates and `tanh` for candidate values. `Note` that if you pass
+    /// `activators::Type::Custom` the default value (`activators::Type::Tanh`) will be used.
+    ///
+    /// * `func: neuroflow::activators::Type` - enum element that indicates which
+    ///   function to use;
+    /// * `return -> &mut Recurrent` - link on the current struct.
+    ///
+    /// # Panics
+    ///
+    /// If function has trainable parameters, e.g. `activators::Type::PRelu`,
+    /// since they are not trained through time, or if function of
+    /// `activators::Type::Named` is not registered.
+    pub fn activation(&mut self, func: activators::Type) -> &mut Recurrent{
+        assert!(func.parameter().is_none(), "activation function {:?} with trainable parameters \
+                is not supported by recurrent cells", func);
+        self.act = ActivationContainer::from_type(&func);
+        self.act_type = match func {
+            activators::Type::Custom => activators::Type::Tanh,
+            t => t
+        };
+        self
+    }
+
+    /// Set the learning rate of network.
+    ///
+    /// * `learning_rate: f64` - learning rate;
+    /// * `return -> &mut Recurrent` - link on the current struct.
+    pub fn learning_rate(&mut self, learning_rate: f64) -> &mut Recurrent {
+        self.learn_rate = learning_rate;
+        self
+    }
+
+    /// Set the momentum of network.
+    ///
+    /// * `momentum: f64` - momentum;
+    /// * `return -> &mut Recurrent` - link on the current struct.
+    pub fn momentum(&mut self, momentum: f64) -> &mut Recurrent {
+        self.momentum = momentum;
+        self
+    }
+
+    /// Set the amount of time steps through which error is propagated back.
+    /// `0` means that error is propagated through the whole sequence.
+    ///
+    /// * `steps: usize` - truncation length;
+    /// * `return -> &mut Recurrent` - link on the current struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use neuroflow::recurrent::{Recurrent, Cell};
+    /// # let mut nn = Recurrent::new(Cell::Lstm, &[1, 3, 1]);
+    /// nn.bptt(10);
+    /// ```
+    pub fn bptt(&mut self, steps: usize) -> &mut Recurrent {
+        self.bptt = steps;
+        self
+    }
+
+    /// Set the maximal absolute value of gradient. Greater gradients are clipped
+    /// in order to prevent their explosion.
+    ///
+    /// * `value: f64` - maximal absolute value of gradient;
+    /// * `return -> &mut Recurrent` - link on the current struct.
+    pub fn clip(&mut self, value: f64) -> &mut Recurrent {
+        self.clip = value;
+        self
+    }
+
+    /// Get current training error. It is the error of the last fitted sequence
+    ///
+    /// * `return -> f64` - training error
+    pub fn get_error(&self) -> f64{
+        self.error
+    }
+}
+
+impl Transform for Recurrent{
+    fn after(&mut self) -> Result<(), ErrorKind>{
+        self.act = ActivationContainer::restore(&self.act_type)?;
+        self.reset();
+        Ok(())
+    }
+}
@@ -0,0 +1,309 @@
+//! Module contains hyperparameter search for `FeedForward` neural networks.
+//!
+//! Search builds network for each combination of parameters from the given
+//! space (grid search) or for random combinations (random search), trains it
+//! on training part of `DataSet` divided by `DataSet::divide` and scores it
+//! on test part by the given function. Trials may be run in several threads.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::data::DataSet;
+//! use neuroflow::metrics;
+//! use neuroflow::search::{Search, Space, Strategy};
+//!
+//! let mut data = DataSet::new();
+//! for i in 0..20 {
+//!     let x = i as f64 / 20.0;
+//!     data.push(&[x], &[x * x]);
+//! }
+//!
+//! let mut space = Space::new();
+//! space.architecture(&[1, 3, 1])
+//!     .architecture(&[1, 6, 1])
+//!     .learning_rates(&[0.01, 0.1]);
+//!
+//! /* Score is mean absolute error, the lower the better */
+//! let results = Search::new(space)
+//!     .strategy(Strategy::Grid)
+//!     .iterations(1_000)
+//!     .threads(2)
+//!     .run(&data, metrics::mae);
+//!
+//! println!("best parameters: {:?}", results.trials[0].params);
+//! let mut best = results.best;
+//! ```
+
+use std::cmp::Ordering;
+use std::sync::atomic::{self, AtomicUsize};
+use std::thread;
+
+use rand::{thread_rng, Rng};
+
+use data::DataSet;
+use activators;
+
+use FeedForward;
+
+/// Parameters of a single network.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Params {
+    /// Architecture in the same form as for `FeedForward::new`
+    pub architecture: Vec<i32>,
+    /// Learning rate
+    pub learning_rate: f64,
+    /// Momentum
+    pub momentum: f64,
+    /// Activation function
+    pub activation: activators::Type,
+}
+
+impl Params {
+    /// Create untrained network with these parameters.
+    pub fn build(&self) -> FeedForward {
+        let mut nn = FeedForward::new(&self.architecture);
+        nn.learning_rate(self.learning_rate)
+            .momentum(self.momentum)
+            .activation(self.activation.clone());
+        nn
+    }
+}
+
+/// Space of parameters. Each parameter takes values from its list.
+/// If list of parameter is not set, the default value of `FeedForward` is used.
+pub struct Space {
+    architectures: Vec<Vec<i32>>,
+    learning_rates: Vec<f64>,
+    momenta: Vec<f64>,
+    activations: Vec<activators::Type>,
+}
+
+impl Space {
+    /// Create empty space. At least one architecture must be added.
+    pub fn new() -> Space {
+        Space {architectures: Vec::new(), learning_rates: vec![0.1], momenta: vec![0.1],
+            activations: vec![activators::Type::Tanh]}
+    }
+
+    /// Add architecture to space.
+    pub fn architecture(&mut self, architecture: &[i32]) -> &mut Space {
+        self.architectures.push(architecture.to_vec());
+        self
+    }
+
+    /// Set values of learning rate.
+    pub fn learning_rates(&mut self, values: &[f64]) -> &mut Space {
+        self.learning_rates = values.to_vec();
+        self
+    }
+
+    /// Set values of momentum.
+    pub fn momenta(&mut self, values: &[f64]) -> &mut Space {
+        self.momenta = values.to_vec();
+        self
+    }
+
+    /// Set activation functions.
+    pub fn activations(&mut self, values: &[activators::Type]) -> &mut Space {
+        self.activations = values.to_vec();
+        self
+    }
+
+    /// All combinations of parameters.
+    pub fn grid(&self) -> Vec<Params> {
+        let mut res = Vec::new();
+        for architecture in self.architectures.iter(){
+            for &learning_rate in self.learning_rates.iter(){
+                for &momentum in self.momenta.iter(){
+                    for activation in self.activations.iter(){
+                        res.push(Params {architecture: architecture.clone(), learning_rate, momentum,
+                            activation: activation.clone()});
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    /// Random combinations of parameters.
+    ///
+    /// * `count: usize` - amount of combinations;
+    /// * `return -> Vec<Params>` - combinations, they may repeat.
+    pub fn sample(&self, count: usize) -> Vec<Params> {
+        let mut rng = thread_rng();
+        (0..count).map(|_| Params {
+            architecture: self.architectures[rng.gen_range(0..self.architectures.len())].clone(),
+            learning_rate: self.learning_rates[rng.gen_range(0..self.learning_rates.len())],
+            momentum: self.momenta[rng.gen_range(0..self.momenta.len())],
+            activation: self.activations[rng.gen_range(0..self.activations.len())].clone(),
+        }).collect()
+    }
+}
+
+impl Default for Space {
+    fn default() -> Space {
+        Space::new()
+    }
+}
+
+/// Strategy of search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    /// Try all combinations of parameters
+    Grid,
+    /// Try the given amount of random combinations of parameters
+    Random(usize),
+}
+
+/// Result of a single trial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trial {
+    /// Parameters of network
+    pub params: Params,
+    /// Score of trained network on test set
+    pub score: f64,
+}
+
+/// Results of search.
+pub struct Results {
+    /// Trials ranked from the best to the worst
+    pub trials: Vec<Trial>,
+    /// Trained network with the best score
+    pub best: FeedForward,
+}
+
+/// Hyperparameter search.
+pub struct Search {
+    space: Space,
+    strategy: Strategy,
+    iterations: i64,
+    proportion: f64,
+    threads: usize,
+    maximize: bool,
+}
+
+impl Search {
+    /// Create grid search over the given space with 10000 training iterations,
+    /// 20% of data in test set and single thread.
+    pub fn new(space: Space) -> Search {
+        Search {space, strategy: Strategy::Grid, iterations: 10_000, proportion: 0.2, threads: 1, maximize: false}
+    }
+
+    /// Choose strategy of search.
+    pub fn strategy(&mut self, strategy: Strategy) -> &mut Search {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Set amount of training iterations of each network.
+    pub fn iterations(&mut self, iterations: i64) -> &mut Search {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Set share of data which is separated to test set.
+    pub fn proportion(&mut self, proportion: f64) -> &mut Search {
+        self.proportion = proportion;
+        self
+    }
+
+    /// Set amount of threads for running of trials.
+    pub fn threads(&mut self, threads: usize) -> &mut Search {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Consider higher scores as better, e.g. for accuracy.
+    /// By default the lower score is the better.
+    pub fn maximize(&mut self, maximize: bool) -> &mut Search {
+        self.maximize = maximize;
+        self
+    }
+
+    /// Run search. Copy of data is divided by `DataSet::divide` once, so all networks
+    /// are trained and scored on the same sets. Trials whose score is `NaN`,
+    /// e.g. because training diverged, are ranked the last.
+    ///
+    /// * `data: &DataSet` - data to be divided into training and test sets, it is not changed;
+    /// * `score: F` - function that scores trained network on test set;
+    /// * `return -> Results` - ranked trials and the best network.
+    pub fn run<F>(&self, data: &DataSet, score: F) -> Results
+        where F: Fn(&mut FeedForward, &DataSet) -> f64 + Sync{
+        let params = match self.strategy {
+            Strategy::Grid => self.space.grid(),
+            Strategy::Random(count) => self.space.sample(count),
+        };
+        assert!(!params.is_empty(), "space of parameters must contain at least one architecture");
+
+        let mut data = data.clone();
+        data.divide(self.proportion);
+        let train = &data;
+        let test = data.test_set();
+
+        let next = AtomicUsize::new(0);
+        let worker = || {
+            let mut trials = Vec::new();
+            let mut best: Option<(f64, FeedForward)> = None;
+
+            loop {
+                let i = next.fetch_add(1, atomic::Ordering::SeqCst);
+                if i >= params.len() {
+                    break;
+                }
+
+                let mut nn = params[i].build();
+                nn.train(train, self.iterations);
+                let s = score(&mut nn, &test);
+
+                let replace = match best {
+                    Some(ref b) => self.better(s, b.0),
+                    None => true
+                };
+                if replace {
+                    best = Some((s, nn));
+                }
+                trials.push(Trial {params: params[i].clone(), score: s});
+            }
+            (trials, best)
+        };
+
+        let parts = thread::scope(|scope| {
+            let handles: Vec<_> = (0..self.threads.min(params.len())).map(|_| scope.spawn(worker)).collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+        });
+
+        let mut trials = Vec::new();
+        let mut best: Option<(f64, FeedForward)> = None;
+        for (t, b) in parts{
+            trials.extend(t);
+            if let Some(b) = b {
+                let replace = match best {
+                    Some(ref c) => self.better(b.0, c.0),
+                    None => true
+                };
+                if replace {
+                    best = Some(b);
+                }
+            }
+        }
+
+        trials.sort_by(|a, b| self.order(a.score, b.score));
+
+        Results {trials, best: best.unwrap().1}
+    }
+
+    /// Order scores from the best to the worst, `NaN` is the worst score
+    fn order(&self, a: f64, b: f64) -> Ordering {
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            _ if self.maximize => b.total_cmp(&a),
+            _ => a.total_cmp(&b)
+        }
+    }
+
+    fn better(&self, a: f64, b: f64) -> bool {
+        self.order(a, b) == Ordering::Less
+    }
+}
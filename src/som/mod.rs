@@ -0,0 +1,341 @@
+//! Module contains self-organizing map (Kohonen network) for unsupervised
+//! clustering and visualisation of data.
+//!
+//! Map is a grid of units. Each unit has the weight vector of the same length as
+//! input vectors. While training, the unit that is the nearest to the sample (winner)
+//! and its neighbours on the grid are moved towards the sample. Learning rate and
+//! neighbourhood radius decrease during training.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::som::{SelfOrganizingMap, Topology, Neighbourhood, Decay};
+//! use neuroflow::data::DataSet;
+//!
+//! let mut data = DataSet::new();
+//! data.push(&[0.1, 0.2, 0.1], &[]);
+//! data.push(&[0.9, 0.8, 0.9], &[]);
+//!
+//! let mut som = SelfOrganizingMap::new(5, 5, 3);
+//! som.topology(Topology::Hexagonal)
+//!     .neighbourhood(Neighbourhood::Gaussian)
+//!     .decay(Decay::Exponential)
+//!     .learning_rate(0.5)
+//!     .train(&data, 1_000);
+//!
+//! let (row, col) = som.winner(&[0.1, 0.2, 0.1]);
+//! let u = som.u_matrix();
+//! ```
+
+use csv;
+use rand::{thread_rng, Rng};
+use rand::distributions::Uniform;
+
+use math;
+use data::Extractable;
+
+use ErrorKind;
+use Transform;
+
+/// Determine the arrangement of units on the grid.
+///
+/// * `Rectangular` - each unit has 4 nearest neighbours;
+/// * `Hexagonal` - odd rows are shifted by half of unit, so each unit has 6 nearest neighbours.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Topology {
+    Rectangular,
+    Hexagonal
+}
+
+/// Determine the function of grid distance `d` and radius `r` that scales
+/// the update of units.
+///
+/// * `Gaussian` - `exp(-d^2 / (2r^2))`;
+/// * `Bubble` - `1` if `d <= r` else `0`;
+/// * `MexicanHat` - `(1 - d^2 / r^2) exp(-d^2 / (2r^2))`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Neighbourhood {
+    Gaussian,
+    Bubble,
+    MexicanHat
+}
+
+/// Determine how learning rate and radius decrease from their initial values
+/// during training. `t` is the current iteration and `T` is amount of iterations.
+///
+/// * `Linear` - `v (1 - t/T)`;
+/// * `Exponential` - `v 0.01^(t/T)`, i.e. down to 1% of initial value;
+/// * `InverseTime` - `v / (1 + 100 t/T)`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Decay {
+    Linear,
+    Exponential,
+    InverseTime
+}
+
+impl Decay {
+    fn apply(&self, value: f64, t: f64) -> f64 {
+        match *self {
+            Decay::Linear => value * (1.0 - t),
+            Decay::Exponential => value * 0.01f64.powf(t),
+            Decay::InverseTime => value / (1.0 + 100.0 * t),
+        }
+    }
+}
+
+/// Self-organizing map.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::som::SelfOrganizingMap;
+///
+/// let mut som = SelfOrganizingMap::new(3, 4, 2);
+/// som.fit(&[0.5, 0.1], 0.1, 1.0);
+///
+/// let w: Vec<f64> = som.weights(0, 0).to_vec();
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct SelfOrganizingMap {
+    rows: usize,
+    cols: usize,
+    w: Vec<Vec<f64>>,
+
+    topology: Topology,
+    neighbourhood: Neighbourhood,
+    decay: Decay,
+    learn_rate: f64,
+    radius: f64,
+}
+
+impl SelfOrganizingMap {
+    /// The constructor of `SelfOrganizingMap` struct. Weights are initialized
+    /// with random values from `[-1, 1]`.
+    ///
+    /// * `rows: usize` - amount of rows of grid;
+    /// * `cols: usize` - amount of columns of grid;
+    /// * `dim: usize` - length of input vectors;
+    /// * `return` - `SelfOrganizingMap` struct.
+    pub fn new(rows: usize, cols: usize, dim: usize) -> SelfOrganizingMap {
+        SelfOrganizingMap {rows, cols, w: math::random(rows * cols, dim, 1.0),
+            topology: Topology::Rectangular,
+            neighbourhood: Neighbourhood::Gaussian,
+            decay: Decay::Exponential,
+            learn_rate: 0.1,
+            radius: usize::max(rows, cols) as f64 / 2.0}
+    }
+
+    /// Initialize weights of units with randomly chosen input vectors of data.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `return -> &mut SelfOrganizingMap` - link on the current struct.
+    pub fn init<T>(&mut self, data: &T) -> &mut SelfOrganizingMap where T: Extractable{
+        for i in 0..self.w.len(){
+            self.w[i] = data.rand().0.clone();
+        }
+        self
+    }
+
+    /// Position of unit on the plane
+    fn position(&self, unit: usize) -> (f64, f64) {
+        let (r, c) = ((unit / self.cols) as f64, (unit % self.cols) as f64);
+        match self.topology {
+            Topology::Rectangular => (r, c),
+            Topology::Hexagonal => (r * 3f64.sqrt() / 2.0, c + 0.5 * (r % 2.0)),
+        }
+    }
+
+    /// Distance between two units on the grid
+    fn grid_distance(&self, a: usize, b: usize) -> f64 {
+        let (pa, pb) = (self.position(a), self.position(b));
+        ((pa.0 - pb.0).powi(2) + (pa.1 - pb.1).powi(2)).sqrt()
+    }
+
+    fn best(&self, x: &[f64]) -> usize {
+        let mut best = (0, f64::MAX);
+        for i in 0..self.w.len(){
+            let d = math::distance2(&self.w[i], x);
+            if d < best.1 {
+                best = (i, d);
+            }
+        }
+        best.0
+    }
+
+    /// Train map by input vectors of data. Learning rate and radius decrease
+    /// from their initial values according to the decay schedule.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `iterations: i64` - iterations count.
+    ///
+    /// # Panics
+    ///
+    /// If data is empty.
+    pub fn train<T>(&mut self, data: &T, iterations: i64) where T: Extractable{
+        assert!(data.len() > 0, "map must be trained on at least one vector");
+
+        let mut rng = thread_rng();
+        let range = Uniform::new(0, data.len());
+
+        for i in 0..iterations{
+            let t = i as f64 / iterations as f64;
+            let rate = self.decay.apply(self.learn_rate, t);
+            let radius = self.decay.apply(self.radius, t);
+
+            let x = data.get(rng.sample(range)).0;
+            self.fit(x, rate, radius);
+        }
+    }
+
+    /// Move the winner unit and its neighbours towards the vector.
+    ///
+    /// * `x: &[f64]` - input vector;
+    /// * `rate: f64` - learning rate;
+    /// * `radius: f64` - radius of neighbourhood.
+    #[allow(clippy::needless_range_loop)]
+    pub fn fit(&mut self, x: &[f64], rate: f64, radius: f64){
+        let winner = self.best(x);
+        let r2 = f64::max(radius, 1e-9).powi(2);
+
+        for i in 0..self.w.len(){
+            let d2 = self.grid_distance(winner, i).powi(2);
+            let h = match self.neighbourhood {
+                Neighbourhood::Gaussian => (-d2 / (2.0 * r2)).exp(),
+                Neighbourhood::Bubble => if d2 <= r2 { 1.0 } else { 0.0 },
+                Neighbourhood::MexicanHat => (1.0 - d2 / r2) * (-d2 / (2.0 * r2)).exp(),
+            };
+
+            if h != 0.0 {
+                for k in 0..x.len(){
+                    self.w[i][k] += rate * h * (x[k] - self.w[i][k]);
+                }
+            }
+        }
+    }
+
+    /// Find unit which weights are the nearest to the vector.
+    ///
+    /// * `x: &[f64]` - input vector;
+    /// * `return -> (usize, usize)` - row and column of winner unit.
+    pub fn winner(&self, x: &[f64]) -> (usize, usize) {
+        let i = self.best(x);
+        (i / self.cols, i % self.cols)
+    }
+
+    /// Get weights of unit.
+    ///
+    /// * `row: usize` - row of unit;
+    /// * `col: usize` - column of unit;
+    /// * `return -> &[f64]` - weights of unit.
+    pub fn weights(&self, row: usize, col: usize) -> &[f64] {
+        &self.w[row * self.cols + col]
+    }
+
+    /// Mean distance between input vectors of data and weights of their winner units.
+    ///
+    /// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+    /// * `return -> f64` - quantization error.
+    pub fn quantization_error<T>(&self, data: &T) -> f64 where T: Extractable{
+        let mut error = 0.0;
+        for i in 0..data.len(){
+            let x = data.get(i).0;
+            error += math::distance2(&self.w[self.best(x)], x).sqrt();
+        }
+        error / data.len() as f64
+    }
+
+    /// Unified distance matrix. Each element is the mean distance between weights
+    /// of unit and weights of its nearest neighbours on the grid. High values
+    /// show borders between clusters.
+    ///
+    /// * `return -> Vec<Vec<f64>>` - matrix with the size of grid.
+    pub fn u_matrix(&self) -> Vec<Vec<f64>> {
+        let mut u = math::zeros(self.rows, self.cols);
+
+        for i in 0..self.w.len(){
+            let (mut sum, mut count) = (0.0, 0);
+            for j in 0..self.w.len(){
+                if i != j && self.grid_distance(i, j) < 1.0 + 1e-9 {
+                    sum += math::distance2(&self.w[i], &self.w[j]).sqrt();
+                    count += 1;
+                }
+            }
+            if count > 0 {
+                u[i / self.cols][i % self.cols] = sum / count as f64;
+            }
+        }
+        u
+    }
+
+    /// Save unified distance matrix to `csv` file without header.
+    ///
+    /// * `file_path: &str` - path to the file;
+    /// * `return -> Result<(), ErrorKind>` - result of operation.
+    pub fn save_u_matrix(&self, file_path: &str) -> Result<(), ErrorKind> {
+        let mut file = csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_path(file_path)
+            .map_err(|e| ErrorKind::StdError(Box::new(e)))?;
+
+        for row in self.u_matrix(){
+            let record: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+            file.write_record(&record).map_err(|e| ErrorKind::StdError(Box::new(e)))?;
+        }
+        file.flush().map_err(ErrorKind::IO)
+    }
+
+    /// Choose topology of grid.
+    ///
+    /// * `topology: Topology` - topology;
+    /// * `return -> &mut SelfOrganizingMap` - link on the current struct.
+    pub fn topology(&mut self, topology: Topology) -> &mut SelfOrganizingMap {
+        self.topology = topology;
+        self
+    }
+
+    /// Choose neighbourhood function.
+    ///
+    /// * `neighbourhood: Neighbourhood` - neighbourhood function;
+    /// * `return -> &mut SelfOrganizingMap` - link on the current struct.
+    pub fn neighbourhood(&mut self, neighbourhood: Neighbourhood) -> &mut SelfOrganizingMap {
+        self.neighbourhood = neighbourhood;
+        self
+    }
+
+    /// Choose decay schedule of learning rate and radius.
+    ///
+    /// * `decay: Decay` - decay schedule;
+    /// * `return -> &mut SelfOrganizingMap` - link on the current struct.
+    pub fn decay(&mut self, decay: Decay) -> &mut SelfOrganizingMap {
+        self.decay = decay;
+        self
+    }
+
+    /// Set the initial learning rate.
+    ///
+    /// * `learning_rate: f64` - learning rate;
+    /// * `return -> &mut SelfOrganizingMap` - link on the current struct.
+    pub fn learning_rate(&mut self, learning_rate: f64) -> &mut SelfOrganizingMap {
+        self.learn_rate = learning_rate;
+        self
+    }
+
+    /// Set the initial radius of neighbourhood. By default it is the half
+    /// of the largest side of grid.
+    ///
+    /// * `radius: f64` - radius;
+    /// * `return -> &mut SelfOrganizingMap` - link on the current struct.
+    pub fn radius(&mut self, radius: f64) -> &mut SelfOrganizingMap {
+        self.radius = radius;
+        self
+    }
+
+    /// Get size of grid
+    ///
+    /// * `return -> (usize, usize)` - amount of rows and columns.
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+}
+
+impl Transform for SelfOrganizingMap{}
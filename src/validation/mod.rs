@@ -0,0 +1,150 @@
+//! Module contains cross-validation of `FeedForward` neural networks.
+//!
+//! Data is split into `k` folds. For each fold a fresh network is created by
+//! factory function, trained on the other folds and scored on this fold.
+//!
+//! # Example
+//!
+//! ```rust
+//! use neuroflow::FeedForward;
+//! use neuroflow::data::DataSet;
+//! use neuroflow::{metrics, validation};
+//!
+//! let mut data = DataSet::new();
+//! for i in 0..20 {
+//!     let x = i as f64 / 20.0;
+//!     data.push(&[x], &[x * x]);
+//! }
+//!
+//! let scores = validation::k_fold(&data, 5, 1_000,
+//!     || FeedForward::new(&[1, 4, 1]), metrics::mae);
+//!
+//! println!("error: {:.4} ± {:.4}", scores.mean(), scores.std());
+//! ```
+
+use rand::thread_rng;
+use rand::seq::SliceRandom;
+
+use data::{DataSet, Extractable};
+
+use FeedForward;
+
+/// Scores of cross-validation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scores {
+    /// Score of network on each fold
+    pub folds: Vec<f64>,
+}
+
+impl Scores {
+    /// Mean score over folds.
+    pub fn mean(&self) -> f64 {
+        self.folds.iter().sum::<f64>() / self.folds.len() as f64
+    }
+
+    /// Sample standard deviation of scores over folds.
+    /// It is `0` if there is only one fold.
+    pub fn std(&self) -> f64 {
+        if self.folds.len() < 2 {
+            return 0.0;
+        }
+
+        let mean = self.mean();
+        let sum: f64 = self.folds.iter().map(|s| (s - mean).powi(2)).sum();
+        (sum / (self.folds.len() - 1) as f64).sqrt()
+    }
+}
+
+/// Train and score network on each fold given by indices of elements
+fn run<T, F, S>(data: &T, folds: &[Vec<usize>], iterations: i64, factory: &mut F, score: &mut S) -> Vec<f64>
+    where T: Extractable, F: FnMut() -> FeedForward, S: FnMut(&mut FeedForward, &DataSet) -> f64{
+    let mut res = Vec::new();
+
+    for f in 0..folds.len(){
+        let mut train = DataSet::new();
+        let mut test = DataSet::new();
+
+        for (g, fold) in folds.iter().enumerate(){
+            let set = if g == f {&mut test} else {&mut train};
+            for &i in fold.iter(){
+                let (x, y) = data.get(i);
+                set.push(x, y);
+            }
+        }
+
+        let mut nn = factory();
+        nn.train(&train, iterations);
+        res.push(score(&mut nn, &test));
+    }
+    res
+}
+
+/// Split shuffled indices of data into `k` folds of nearly equal size
+fn split(len: usize, k: usize) -> Vec<Vec<usize>> {
+    let mut indices: Vec<usize> = (0..len).collect();
+    indices.shuffle(&mut thread_rng());
+
+    let mut folds = vec![Vec::new(); k];
+    for (n, i) in indices.into_iter().enumerate(){
+        folds[n % k].push(i);
+    }
+    folds
+}
+
+/// K-fold cross-validation.
+///
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `k: usize` - amount of folds;
+/// * `iterations: i64` - training iterations of each network;
+/// * `factory: F` - function that creates a fresh network;
+/// * `score: S` - function that scores trained network on test fold;
+/// * `return -> Scores` - score on each fold.
+pub fn k_fold<T, F, S>(data: &T, k: usize, iterations: i64, mut factory: F, mut score: S) -> Scores
+    where T: Extractable, F: FnMut() -> FeedForward, S: FnMut(&mut FeedForward, &DataSet) -> f64{
+    assert!(k >= 2 && k <= data.len(), "amount of folds must be in range [2, data.len()]");
+
+    let folds = split(data.len(), k);
+    Scores {folds: run(data, &folds, iterations, &mut factory, &mut score)}
+}
+
+/// Repeated k-fold cross-validation. Data is shuffled and split into folds
+/// anew on each repetition.
+///
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `k: usize` - amount of folds;
+/// * `repeats: usize` - amount of repetitions;
+/// * `iterations: i64` - training iterations of each network;
+/// * `factory: F` - function that creates a fresh network;
+/// * `score: S` - function that scores trained network on test fold;
+/// * `return -> Scores` - scores on `k * repeats` folds.
+pub fn repeated_k_fold<T, F, S>(data: &T, k: usize, repeats: usize, iterations: i64, mut factory: F, mut score: S) -> Scores
+    where T: Extractable, F: FnMut() -> FeedForward, S: FnMut(&mut FeedForward, &DataSet) -> f64{
+    assert!(k >= 2 && k <= data.len(), "amount of folds must be in range [2, data.len()]");
+
+    let mut res = Vec::new();
+    for _ in 0..repeats{
+        let folds = split(data.len(), k);
+        res.extend(run(data, &folds, iterations, &mut factory, &mut score));
+    }
+    Scores {folds: res}
+}
+
+/// Leave-one-out cross-validation, i.e. k-fold cross-validation
+/// where each fold consists of a single element.
+///
+/// * `data: &T` - the link on data that implements `neuroflow::data::Extractable` trait;
+/// * `iterations: i64` - training iterations of each network;
+/// * `factory: F` - function that creates a fresh network;
+/// * `score: S` - function that scores trained network on the left out element;
+/// * `return -> Scores` - score on each element.
+///
+/// # Panics
+///
+/// If data has less than two elements, so there is nothing to train on.
+pub fn leave_one_out<T, F, S>(data: &T, iterations: i64, mut factory: F, mut score: S) -> Scores
+    where T: Extractable, F: FnMut() -> FeedForward, S: FnMut(&mut FeedForward, &DataSet) -> f64{
+    assert!(data.len() >= 2, "leave-one-out requires at least two elements of data");
+
+    let folds: Vec<Vec<usize>> = (0..data.len()).map(|i| vec![i]).collect();
+    Scores {folds: run(data, &folds, iterations, &mut factory, &mut score)}
+}
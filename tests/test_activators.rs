@@ -0,0 +1,316 @@
+extern crate neuroflow;
+
+use std::fs::{read, write, remove_file};
+use std::thread;
+
+use neuroflow::{FeedForward, ErrorKind};
+use neuroflow::activators;
+use neuroflow::activators::{Type, Activation};
+use neuroflow::io::{save, load};
+
+type Pair = (fn(f64) -> f64, fn(f64) -> f64);
+type WithOutput = (fn(f64) -> f64, fn(f64) -> f64, fn(f64) -> f64);
+type Triple = (fn(f64, f64) -> f64, fn(f64, f64) -> f64, fn(f64, f64) -> f64);
+
+#[test]
+fn derivatives(){
+    let functions: Vec<Pair> = vec![
+        (activators::leaky_relu, activators::der_leaky_relu),
+        (activators::elu, activators::der_elu),
+        (activators::selu, activators::der_selu),
+        (activators::gelu, activators::der_gelu),
+        (activators::swish, activators::der_swish),
+        (activators::softplus, activators::der_softplus),
+        (activators::softsign, activators::der_softsign),
+        (activators::hard_sigm, activators::der_hard_sigm),
+        (activators::hard_tanh, activators::der_hard_tanh),
+        (activators::gaussian, activators::der_gaussian),
+        (activators::identity, activators::der_identity),
+    ];
+
+    let h = 1e-6;
+    for (n, &(func, der)) in functions.iter().enumerate() {
+        for &x in [-3.1, -0.7, -0.2, 0.3, 0.9, 2.2, 4.0].iter() {
+            let numeric = (func(x + h) - func(x - h)) / (2.0 * h);
+            assert!((numeric - der(x)).abs() < 1e-5, "function {} at {}: {} != {}", n, x, numeric, der(x));
+        }
+    }
+}
+
+#[test]
+fn derivatives_from_output(){
+    let functions: Vec<WithOutput> = vec![
+        (activators::sigm, activators::der_sigm, activators::der_sigm_output),
+        (activators::tanh, activators::der_tanh, activators::der_tanh_output),
+        (activators::relu, activators::der_relu, activators::der_relu_output),
+        (activators::leaky_relu, activators::der_leaky_relu, activators::der_leaky_relu_output),
+        (activators::elu, activators::der_elu, activators::der_elu_output),
+        (activators::selu, activators::der_selu, activators::der_selu_output),
+        (activators::softsign, activators::der_softsign, activators::der_softsign_output),
+        (activators::hard_sigm, activators::der_hard_sigm, activators::der_hard_sigm_output),
+        (activators::hard_tanh, activators::der_hard_tanh, activators::der_hard_tanh_output),
+        (activators::identity, activators::der_identity, activators::der_identity_output),
+    ];
+
+    for (n, &(func, der, der_output)) in functions.iter().enumerate() {
+        for &x in [-3.1, -1.0, -0.7, 0.0, 0.3, 1.0, 2.2, 4.0].iter() {
+            assert!((der(x) - der_output(func(x))).abs() < 1e-12, "function {} at {}", n, x);
+        }
+    }
+}
+
+#[test]
+fn values(){
+    assert_eq!(activators::leaky_relu(-2.0), -0.02);
+    assert!((activators::selu(-100.0) + activators::SELU_LAMBDA * activators::SELU_ALPHA).abs() < 1e-12);
+    assert!((activators::gelu(1.0) - 0.841192).abs() < 1e-5);
+    assert!((activators::swish(1.0) - 0.731059).abs() < 1e-5);
+    assert!((activators::softplus(0.0) - 2f64.ln()).abs() < 1e-12);
+    assert_eq!(activators::softplus(1000.0), 1000.0);
+    assert_eq!(activators::hard_sigm(10.0), 1.0);
+    assert_eq!(activators::hard_tanh(-10.0), -1.0);
+    assert_eq!(activators::gaussian(0.0), 1.0);
+}
+
+#[test]
+fn types_serialization(){
+    let types = [Type::LeakyRelu, Type::Elu, Type::Selu, Type::Gelu, Type::Swish, Type::Softplus,
+        Type::Softsign, Type::HardSigmoid, Type::HardTanh, Type::Gaussian, Type::Identity];
+
+    for t in types.iter() {
+        let mut nn = FeedForward::new(&[2, 3, 1]);
+        nn.activation(t.clone());
+        let expected = nn.calc(&[0.3, -0.4]).to_vec();
+
+        save(&mut nn, "types_serialization.flow").unwrap();
+        let mut restored: FeedForward = load("types_serialization.flow").unwrap();
+        remove_file("types_serialization.flow").unwrap();
+
+        assert_eq!(restored.calc(&[0.3, -0.4]).to_vec(), expected, "{:?}", t);
+    }
+}
+
+#[test]
+fn parametric_derivatives(){
+    let functions: Vec<Triple> = vec![
+        (activators::prelu, activators::der_prelu, activators::der_prelu_param),
+        (activators::swish_beta, activators::der_swish_beta, activators::der_swish_beta_param),
+    ];
+
+    let h = 1e-6;
+    for (n, &(func, der, grad)) in functions.iter().enumerate() {
+        for &a in [0.1, 0.7, 1.5].iter() {
+            for &x in [-2.3, -0.4, 0.6, 1.9].iter() {
+                let numeric = (func(x + h, a) - func(x - h, a)) / (2.0 * h);
+                assert!((numeric - der(x, a)).abs() < 1e-5, "function {} at {}", n, x);
+
+                let numeric = (func(x, a + h) - func(x, a - h)) / (2.0 * h);
+                assert!((numeric - grad(x, a)).abs() < 1e-5, "parameter of function {} at {}", n, x);
+            }
+        }
+    }
+}
+
+#[test]
+fn parametric_training(){
+    for t in [Type::PRelu, Type::LearnableSwish].iter() {
+        let mut nn = FeedForward::new(&[1, 4, 1]);
+        nn.activation(t.clone()).learning_rate(0.01);
+
+        let initial = t.parameter().unwrap();
+        assert_eq!(nn.activation_params(1), &[initial; 4]);
+        assert!(nn.activation_params(2).is_empty());
+
+        for i in 0..5_000 {
+            let x = (i % 20) as f64 / 10.0 - 1.0;
+            nn.fit(&[x], &[x.abs()]);
+        }
+        assert!(nn.activation_params(1).iter().any(|&a| a != initial), "{:?}", t);
+
+        nn.bind(1, 2);
+        assert_eq!(nn.activation_params(1).len(), 5);
+        assert_eq!(nn.activation_params(1)[2], initial);
+        nn.unbind(1, 0);
+        assert_eq!(nn.activation_params(1).len(), 4);
+
+        let expected = nn.calc(&[-0.3]).to_vec();
+        save(&mut nn, "parametric_training.flow").unwrap();
+        let mut restored: FeedForward = load("parametric_training.flow").unwrap();
+        remove_file("parametric_training.flow").unwrap();
+
+        assert_eq!(restored.activation_params(1), nn.activation_params(1));
+        assert_eq!(restored.calc(&[-0.3]).to_vec(), expected);
+
+        nn.activation(Type::Tanh);
+        assert!(nn.activation_params(1).is_empty());
+    }
+}
+
+#[test]
+fn parametric_output_layer(){
+    let mut nn = FeedForward::new(&[1, 3, 2]);
+    nn.activation(Type::PRelu);
+    assert!(nn.activation_params(2).is_empty());
+
+    /* former output layer becomes hidden one and gets parameters */
+    nn.push_layer(4);
+    assert_eq!(nn.activation_params(2), &[activators::PRELU_SLOPE; 2]);
+    assert!(nn.activation_params(3).is_empty());
+
+    nn.truncate(2);
+    assert!(nn.activation_params(2).is_empty());
+
+    /* the only layer is activated */
+    let mut single = FeedForward::new(&[2, 3]);
+    single.activation(Type::PRelu);
+    assert_eq!(single.activation_params(1).len(), 3);
+}
+
+#[test]
+fn registered_activation(){
+    fn cube(x: f64) -> f64{
+        x.powi(3)
+    }
+
+    fn der_cube(x: f64) -> f64{
+        3.0 * x.powi(2)
+    }
+
+    activators::register("test_cube", (cube, der_cube));
+    assert!(activators::registered("test_cube").is_some());
+    assert!(activators::registered("test_missing").is_none());
+
+    let mut nn = FeedForward::new(&[2, 3, 1]);
+    nn.activation(Type::Named("test_cube".to_string()));
+    let expected = nn.calc(&[0.3, -0.4]).to_vec();
+
+    save(&mut nn, "registered_activation.flow").unwrap();
+    let mut restored: FeedForward = load("registered_activation.flow").unwrap();
+    remove_file("registered_activation.flow").unwrap();
+    assert_eq!(restored.calc(&[0.3, -0.4]).to_vec(), expected);
+}
+
+#[test]
+fn unknown_activation(){
+    fn zero(_x: f64) -> f64{
+        0.0
+    }
+
+    let mut nn = FeedForward::new(&[1, 2, 1]);
+    nn.custom_activation(zero, zero);
+
+    save(&mut nn, "unknown_activation.flow").unwrap();
+    let res: Result<FeedForward, ErrorKind> = load("unknown_activation.flow");
+    remove_file("unknown_activation.flow").unwrap();
+    match res {
+        Err(ErrorKind::Activation(Type::Custom)) => (),
+        _ => panic!("network with unnamed custom activation must not be loaded"),
+    }
+
+    /* imitate file of a program which has registered function `test_saved_b` */
+    activators::register("test_saved_a", (zero, zero));
+    nn.activation(Type::Named("test_saved_a".to_string()));
+    save(&mut nn, "unknown_activation.flow").unwrap();
+
+    let mut data = read("unknown_activation.flow").unwrap();
+    let pos = data.windows(12).position(|w| w == b"test_saved_a").unwrap();
+    data[pos + 11] = b'b';
+    write("unknown_activation.flow", &data).unwrap();
+
+    let res: Result<FeedForward, ErrorKind> = load("unknown_activation.flow");
+    remove_file("unknown_activation.flow").unwrap();
+    match res {
+        Err(ErrorKind::Activation(Type::Named(name))) => assert_eq!(name, "test_saved_b"),
+        _ => panic!("network with unregistered named activation must not be loaded"),
+    }
+}
+
+#[test]
+fn try_unregistered_activation(){
+    let mut nn = FeedForward::new(&[1, 2, 1]);
+    nn.activation(Type::Relu);
+    match nn.try_activation(Type::Named("test_unregistered".to_string())) {
+        Err(ErrorKind::Activation(Type::Named(name))) => assert_eq!(name, "test_unregistered"),
+        _ => panic!("unregistered activation must not be set"),
+    }
+
+    /* network keeps its activation function */
+    nn.calc(&[-1.0]);
+    assert!(nn.activations(1).iter().all(|&y| y >= 0.0));
+}
+
+#[test]
+#[should_panic]
+fn unregistered_activation(){
+    let mut nn = FeedForward::new(&[1, 2, 1]);
+    nn.activation(Type::Named("test_unregistered".to_string()));
+}
+
+struct Scaled {
+    factor: f64,
+}
+
+impl Activation for Scaled {
+    fn value(&self, x: f64) -> f64 {
+        self.factor * x.tanh()
+    }
+
+    fn derivative(&self, x: f64) -> f64 {
+        self.factor * (1.0 - x.tanh().powi(2))
+    }
+
+    fn derivative_from_output(&self, _x: f64, y: f64) -> f64 {
+        self.factor * (1.0 - (y / self.factor).powi(2))
+    }
+}
+
+#[test]
+fn activation_trait(){
+    let mut a = FeedForward::new(&[1, 3, 1]);
+    let mut b = a.clone();
+    let mut c = a.clone();
+
+    a.activator(Scaled {factor: 2.0});
+    let factor = 2.0;
+    b.custom_activation(move |x: f64| factor * x.tanh(), move |x: f64| factor * (1.0 - x.tanh().powi(2)));
+    c.activation(Type::Tanh);
+
+    for i in 0..100 {
+        let x = i as f64 / 50.0 - 1.0;
+        a.fit(&[x], &[x * x]);
+        b.fit(&[x], &[x * x]);
+    }
+    assert_eq!(a.calc(&[0.4]), b.calc(&[0.4]));
+    assert!(a.activations(1).iter().all(|y| y.abs() <= 2.0));
+    assert_eq!(a.activations(1).len(), 3);
+
+    /* hidden layer of `a` with the same weights gives doubled output of `c` */
+    let mut d = a.clone();
+    d.activation(Type::Tanh);
+    d.calc(&[0.4]);
+    for (y, t) in a.activations(1).to_vec().iter().zip(d.activations(1)) {
+        assert!((y - 2.0 * t).abs() < 1e-12);
+    }
+
+    /* networks with custom functions remain sendable between threads */
+    let handle = thread::spawn(move || {
+        a.fit(&[0.1], &[0.2]);
+        a
+    });
+    handle.join().unwrap();
+}
+
+#[test]
+fn registered_trait(){
+    activators::register("test_scaled", Scaled {factor: 3.0});
+    let f = activators::registered("test_scaled").unwrap();
+    assert_eq!(f.value(1.0), 3.0 * 1f64.tanh());
+    assert!((f.derivative_from_output(0.0, f.value(1.0)) - f.derivative(1.0)).abs() < 1e-12);
+
+    let mut nn = FeedForward::new(&[1, 2, 1]);
+    nn.set_weights(1, &[vec![0.5], vec![-2.0]]).unwrap();
+    nn.set_biases(1, &[0.25, 1.0]).unwrap();
+    nn.activation(Type::Named("test_scaled".to_string()));
+    nn.calc(&[1.0]);
+    assert_eq!(nn.activations(1), &[3.0 * 0.75f64.tanh(), 3.0 * (-1f64).tanh()]);
+}
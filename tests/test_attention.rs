@@ -0,0 +1,83 @@
+extern crate neuroflow;
+extern crate rand;
+
+use std::path::Path;
+use std::fs::remove_file;
+
+use neuroflow::attention::{Transformer, positional_encoding};
+use neuroflow::data::{SequenceSet, SequenceExtractable};
+use neuroflow::io::{save, load};
+
+
+fn argmax(v: &[f64]) -> usize {
+    let mut max_i = 0;
+    for i in 1..v.len(){
+        if v[i] > v[max_i]{
+            max_i = i;
+        }
+    }
+    max_i
+}
+
+#[test]
+fn order_classification(){
+    // Class depends on whether the sequence is increasing or decreasing,
+    // so it can be recognized only with positional information
+    let mut data = SequenceSet::new();
+    for _ in 0..40{
+        let mut x: Vec<f64> = (0..5).map(|_| rand::random::<f64>()).collect();
+        x.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let inc: Vec<Vec<f64>> = x.iter().map(|v| vec![*v]).collect();
+        let dec: Vec<Vec<f64>> = x.iter().rev().map(|v| vec![*v]).collect();
+        data.push(&inc, &[vec![1.0, 0.0]]);
+        data.push(&dec, &[vec![0.0, 1.0]]);
+    }
+
+    let mut nn = Transformer::new(1, 8, 2, 1, 16, &[8, 2]);
+    nn.learning_rate(0.02)
+        .momentum(0.0)
+        .train(&data, 5_000);
+
+    let mut correct = 0;
+    for i in 0..data.len(){
+        let (x, y) = data.get(i);
+        if argmax(nn.calc(x)) == argmax(&y[0]){
+            correct += 1;
+        }
+    }
+    println!("correct: {} of {}", correct, data.len());
+    assert!(correct as f64 >= 0.9 * data.len() as f64);
+}
+
+#[test]
+fn positional_encoding_values(){
+    let pe = positional_encoding(3, 4);
+
+    assert_eq!(pe.len(), 3);
+    assert_eq!(pe[0], vec![0.0, 1.0, 0.0, 1.0]);
+    assert_eq!(pe[1][0], 1f64.sin());
+    assert_eq!(pe[1][1], 1f64.cos());
+    assert_eq!(pe[2][2], (2.0 / 100f64).sin());
+}
+
+#[test]
+fn saving_and_loading(){
+    let file_path = "testtransformer.nn";
+    let xs = vec![vec![0.5, -0.3], vec![0.1, 0.9], vec![-0.7, 0.2]];
+    let mut nn = Transformer::new(2, 4, 2, 2, 8, &[3]);
+    nn.fit(&xs, &[1.0, 0.0, 0.0]);
+
+    save(&mut nn, file_path).unwrap();
+    let mut new_nn: Transformer = load(file_path).unwrap();
+    remove_file(Path::new(file_path)).unwrap();
+
+    assert_eq!(nn.calc(&xs).to_vec(), new_nn.calc(&xs).to_vec());
+}
+
+#[test]
+#[should_panic(expected = "at least one vector")]
+fn empty_sequence(){
+    let mut nn = Transformer::new(2, 4, 2, 2, 8, &[3]);
+    nn.calc(&[]);
+}
@@ -0,0 +1,85 @@
+extern crate neuroflow;
+
+use std::path::Path;
+use std::fs::remove_file;
+
+use neuroflow::autoencoder::Autoencoder;
+use neuroflow::data::DataSet;
+use neuroflow::io::{save, load};
+
+
+fn line() -> DataSet {
+    let mut data = DataSet::new();
+    let mut t = -0.5;
+    while t <= 0.5 {
+        data.push(&[t, 0.5 * t, -t], &[]);
+        t += 0.02;
+    }
+    data
+}
+
+#[test]
+fn anomaly_detection(){
+    let data = line();
+    let mut ae = Autoencoder::new(&[3, 1]);
+
+    ae.learning_rate(0.01)
+        .momentum(0.0)
+        .train(&data, 20_000);
+
+    let threshold = ae.calibrate(&data, 0.95);
+    println!("threshold: {:.5}", threshold);
+
+    assert_eq!(ae.threshold(), Some(threshold));
+    assert!(!ae.is_anomaly(&[0.1, 0.05, -0.1]));
+    assert!(ae.is_anomaly(&[0.5, -0.5, 0.5]));
+
+    let errors = ae.errors(&data);
+    let normal = errors.iter().filter(|e| **e <= threshold).count();
+    assert!(normal as f64 >= 0.95 * errors.len() as f64);
+}
+
+#[test]
+fn encode_and_decode(){
+    let mut ae = Autoencoder::new(&[4, 3, 2]);
+    let x = [0.1, 0.2, 0.3, 0.4];
+
+    let code = ae.encode(&x);
+    assert_eq!(code.len(), 2);
+
+    let restored = ae.decode(&code);
+    assert_eq!(restored, ae.calc(&x).to_vec());
+}
+
+#[test]
+#[should_panic]
+fn anomaly_without_threshold(){
+    let mut ae = Autoencoder::new(&[2, 1]);
+    ae.is_anomaly(&[0.1, 0.2]);
+}
+
+#[test]
+fn saving_and_loading(){
+    let file_path = "testautoencoder.nn";
+    let mut ae = Autoencoder::new(&[3, 2]);
+    ae.set_threshold(0.25);
+
+    save(&mut ae, file_path).unwrap();
+    let mut new_ae: Autoencoder = load(file_path).unwrap();
+    remove_file(Path::new(file_path)).unwrap();
+
+    assert_eq!(new_ae.threshold(), Some(0.25));
+    assert_eq!(ae.encode(&[0.1, 0.2, 0.3]), new_ae.encode(&[0.1, 0.2, 0.3]));
+}
+
+#[test]
+fn calibration_with_nan_error(){
+    let mut data = DataSet::new();
+    data.push(&[f64::NAN, 0.0], &[]);
+    data.push(&[0.1, 0.2], &[]);
+
+    let mut ae = Autoencoder::new(&[2, 1]);
+    assert!(ae.errors(&data)[0].is_nan());
+    ae.calibrate(&data, 1.0);
+    assert!(ae.threshold().is_some());
+}
@@ -3,6 +3,10 @@ extern crate neuroflow;
 use neuroflow::FeedForward;
 use neuroflow::data::DataSet;
 use neuroflow::data::Extractable;
+use neuroflow::data::{SequenceSet, SequenceExtractable};
+use neuroflow::data::Splitter;
+use neuroflow::data::{Scaler, DataScaler, Scaling};
+use neuroflow::io::{save, load};
 
 use neuroflow::activators;
 
@@ -33,10 +37,7 @@ fn test_load_from_csv(){
     std::fs::remove_file("test.csv").unwrap();
     match ds {
        Ok(v) => println!("{:?}", v),
-        Err(e) => {
-            println!("{}", e);
-            assert!(false);
-        }
+        Err(e) => panic!("{}", e)
     }
 }
 
@@ -115,6 +116,66 @@ fn test_division(){
     assert_eq!(data.len(), 9);
 }
 
+#[test]
+fn test_splitting(){
+    let mut data = DataSet::new();
+    for i in 0..100 {
+        data.push(&[i as f64], &[if i < 80 {0.0} else {1.0}]);
+    }
+
+    let (train, validation, test) = Splitter::new(0.2, 0.1).split(&data);
+    assert_eq!((train.len(), validation.len(), test.len()), (70, 20, 10));
+
+    /* the same seed gives the same split */
+    let a = Splitter::new(0.2, 0.1).seed(7).split(&data);
+    let b = Splitter::new(0.2, 0.1).seed(7).split(&data);
+    assert_eq!(format!("{:?}", a), format!("{:?}", b));
+
+    /* each element is in exactly one set in original order */
+    let mut all: Vec<f64> = Vec::new();
+    for set in [&a.0, &a.1, &a.2].iter() {
+        let xs: Vec<f64> = (0..set.len()).map(|i| set.get(i).0[0]).collect();
+        assert!(xs.windows(2).all(|w| w[0] < w[1]));
+        all.extend(xs);
+    }
+    all.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(all, (0..100).map(|i| i as f64).collect::<Vec<f64>>());
+}
+
+#[test]
+fn test_stratified_splitting(){
+    let mut data = DataSet::new();
+    for i in 0..100 {
+        let y = if i < 80 {[1.0, 0.0]} else {[0.0, 1.0]};
+        data.push(&[i as f64], &y);
+    }
+
+    let (train, validation, test) = Splitter::new(0.25, 0.25).stratify(true).split(&data);
+    for set in [&train, &validation, &test].iter() {
+        let second = (0..set.len()).filter(|&i| set.get(i).1[1] == 1.0).count();
+        assert_eq!(second * 5, set.len());
+    }
+}
+
+#[test]
+fn test_group_splitting(){
+    let mut data = DataSet::new();
+    let mut groups = Vec::new();
+    for i in 0..100 {
+        data.push(&[(i / 4) as f64], &[0.0]);
+        groups.push(i / 4);
+    }
+
+    let (train, validation, test) = Splitter::new(0.2, 0.2).groups(&groups).seed(1).split(&data);
+    assert_eq!(train.len() + validation.len() + test.len(), 100);
+    assert_eq!((validation.len(), test.len()), (20, 20));
+
+    let ids = |set: &DataSet| (0..set.len()).map(|i| set.get(i).0[0] as usize).collect::<Vec<usize>>();
+    for id in ids(&test) {
+        assert!(!ids(&train).contains(&id) && !ids(&validation).contains(&id));
+    }
+}
+
 #[test]
 fn test_cv(){
     let mut nn = FeedForward::new(&[2, 2, 2]);
@@ -136,4 +197,129 @@ fn test_cv(){
         .train(&data, 30_000);
 
     println!("{:?}", data.cv(&mut nn));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_scalers(){
+    let v = vec![vec![1.0, 0.0, -4.0], vec![2.0, 0.0, 2.0], vec![3.0, 0.0, 1.0], vec![10.0, 0.0, -1.0]];
+    let close = |a: &[f64], b: &[f64]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-12);
+
+    let standard = Scaler::fit(Scaling::Standard, &v);
+    let scaled: Vec<Vec<f64>> = v.iter().map(|x| standard.transform(x)).collect();
+    for k in 0..3 {
+        let mean = scaled.iter().map(|x| x[k]).sum::<f64>() / 4.0;
+        let var = scaled.iter().map(|x| (x[k] - mean).powi(2)).sum::<f64>() / 4.0;
+        assert!(mean.abs() < 1e-12);
+        assert!((var - if k == 1 {0.0} else {1.0}).abs() < 1e-12);
+    }
+
+    let min_max = Scaler::fit(Scaling::MinMax, &v);
+    assert!(close(&min_max.transform(&v[0]), &[0.0, 0.0, 0.0]));
+    assert!(close(&min_max.transform(&v[3]), &[1.0, 0.0, 0.5]));
+
+    /* median 2.5, quartiles 1.75 and 4.75 */
+    let robust = Scaler::fit(Scaling::Robust, &v);
+    assert!(close(&robust.transform(&[2.5, 0.0, 0.0]), &[0.0, 0.0, 0.0]));
+    assert!(close(&robust.transform(&[5.5, 1.0, 0.0]), &[1.0, 1.0, 0.0]));
+
+    let max_abs = Scaler::fit(Scaling::MaxAbs, &v);
+    assert!(close(&max_abs.transform(&v[0]), &[0.1, 0.0, -1.0]));
+    assert_eq!(max_abs.method(), Scaling::MaxAbs);
+
+    for scaler in [standard, min_max, robust, max_abs].iter() {
+        for x in v.iter() {
+            assert!(close(&scaler.inverse_transform(&scaler.transform(x)), x));
+
+            let mut y = x.clone();
+            scaler.transform_mut(&mut y);
+            assert_eq!(y, scaler.transform(x));
+            scaler.inverse_transform_mut(&mut y);
+            assert!(close(&y, x));
+        }
+    }
+}
+
+#[test]
+fn test_robust_scaler_with_nan(){
+    let v = vec![vec![f64::NAN, 1.0], vec![1.0, 2.0], vec![2.0, 3.0], vec![3.0, 10.0]];
+
+    /* values of the second column are still scaled by their median and quartiles */
+    let robust = Scaler::fit(Scaling::Robust, &v);
+    assert_eq!(robust.transform(&[0.0, 2.5])[1], 0.0);
+    assert_eq!(robust.transform(&[0.0, 5.5])[1], 1.0);
+}
+
+#[test]
+#[should_panic(expected = "fitted to vectors of length 2")]
+fn test_scaler_with_longer_vector(){
+    let scaler = Scaler::fit(Scaling::Standard, &[vec![1.0, 2.0], vec![3.0, 5.0]]);
+    scaler.transform(&[1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_data_scaler(){
+    let mut data = DataSet::new();
+    for i in 0..20 {
+        data.push(&[i as f64, 100.0 * i as f64], &[(i * i) as f64]);
+    }
+    data.divide(0.25);
+
+    let scaler = DataScaler::fit(&data, Scaling::MinMax, Some(Scaling::Standard));
+    let copy = scaler.transform(&data);
+    assert!((0..data.len()).any(|i| data.get(i).0[1] > 1.0), "copy must not change data");
+
+    /* scaler is fitted to training set only, test set is transformed as well */
+    let mut x: Vec<f64> = (0..copy.len()).map(|i| copy.get(i).0[1]).collect();
+    x.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!((x[0], x[x.len() - 1]), (0.0, 1.0));
+
+    let test = copy.test_set();
+    let original = data.test_set();
+    for i in 0..test.len() {
+        assert_eq!(test.get(i).0, &scaler.transform_input(original.get(i).0));
+        assert!((scaler.inverse_transform(test.get(i).1)[0] - original.get(i).1[0]).abs() < 1e-9);
+    }
+
+    scaler.transform_mut(&mut data);
+    for i in 0..data.len() {
+        assert_eq!(data.get(i), copy.get(i));
+    }
+
+    let only_inputs = DataScaler::fit(&data, Scaling::MaxAbs, None);
+    assert!(only_inputs.outputs().is_none());
+    assert_eq!(only_inputs.inverse_transform(&[3.5]), vec![3.5]);
+    assert_eq!(only_inputs.inputs().method(), Scaling::MaxAbs);
+
+    save(&mut scaler.clone(), "test_data_scaler.flow").unwrap();
+    let restored: DataScaler = load("test_data_scaler.flow").unwrap();
+    std::fs::remove_file("test_data_scaler.flow").unwrap();
+    assert_eq!(restored, scaler);
+}
+
+#[test]
+fn test_sequence_set(){
+    let mut data = SequenceSet::new();
+    data.push(&[vec![0.1, 0.2], vec![0.3, 0.4]], &[vec![1.0]]);
+    data.push(&[vec![0.5, 0.6], vec![0.7, 0.8]], &[vec![0.0], vec![1.0]]);
+
+    assert_eq!(data.len(), 2);
+    assert_eq!(data.get(1).0[1], vec![0.7, 0.8]);
+    assert_eq!(data.get(1).1.len(), 2);
+
+    data.remove(0);
+    assert_eq!(data.len(), 1);
+    assert_eq!(data.rand().0[0], vec![0.5, 0.6]);
+}
+
+#[test]
+fn test_sequence_set_from_series(){
+    let series = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let data = SequenceSet::from_series(&series, 3, 2);
+
+    assert_eq!(data.len(), 2);
+    assert_eq!(data.get(0).0, &vec![vec![1.0], vec![2.0], vec![3.0]]);
+    assert_eq!(data.get(0).1, &vec![vec![4.0, 5.0]]);
+    assert_eq!(data.get(1).1, &vec![vec![5.0, 6.0]]);
+
+    assert_eq!(SequenceSet::from_series(&series, 6, 1).len(), 0);
+}
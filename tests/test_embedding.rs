@@ -0,0 +1,94 @@
+extern crate neuroflow;
+
+use std::path::Path;
+use std::fs::remove_file;
+
+use neuroflow::embedding::{Embedding, EmbeddingNetwork};
+use neuroflow::data::DataSet;
+use neuroflow::io::{save, load};
+
+
+#[test]
+fn categorical_regression(){
+    const ALLOWED_ERROR: f64 = 0.1;
+    let table = [-0.8, -0.5, -0.2, 0.0, 0.3, 0.6, 0.9, -0.6, 0.4, 0.1];
+    let mut data = DataSet::new();
+
+    for (c, t) in table.iter().enumerate(){
+        for k in 0..5{
+            let x = k as f64 * 0.1;
+            data.push(&[c as f64, x], &[t + 0.5 * x]);
+        }
+    }
+
+    let mut nn = EmbeddingNetwork::new(&[(table.len(), 3)], &[1, 6, 1]);
+    nn.learning_rate(0.02)
+        .momentum(0.0)
+        .train(&data, 40_000);
+
+    for (c, t) in table.iter().enumerate(){
+        let res = nn.calc(&[c], &[0.2])[0];
+        println!("for [{}, 0.2], [{:.3}] -> [{:.3}]", c, t + 0.1, res);
+        assert!((res - t - 0.1).abs() < ALLOWED_ERROR);
+    }
+}
+
+#[test]
+fn sparse_update(){
+    let mut nn = EmbeddingNetwork::new(&[(10, 4), (5, 2)], &[0, 3, 1]);
+
+    let unused = nn.embedding(0).vector(5).to_vec();
+    let used = nn.embedding(0).vector(3).to_vec();
+
+    nn.fit(&[3, 1], &[], &[1.0]);
+
+    assert_eq!(nn.embedding(0).vector(5), &unused[..]);
+    assert_ne!(nn.embedding(0).vector(3), &used[..]);
+}
+
+#[test]
+fn embedding_sizes(){
+    let e = Embedding::new(100, 8);
+    assert_eq!(e.vocabulary(), 100);
+    assert_eq!(e.dim(), 8);
+}
+
+#[test]
+fn saving_and_loading(){
+    let file_path = "testembedding.nn";
+    let mut nn = EmbeddingNetwork::new(&[(10, 4)], &[2, 3, 1]);
+    nn.fit(&[3], &[0.1, 0.2], &[1.0]);
+
+    save(&mut nn, file_path).unwrap();
+    let mut new_nn: EmbeddingNetwork = load(file_path).unwrap();
+    remove_file(Path::new(file_path)).unwrap();
+
+    assert_eq!(nn.embedding(0).vector(3), new_nn.embedding(0).vector(3));
+    assert_eq!(nn.calc(&[3], &[0.1, 0.2])[0], new_nn.calc(&[3], &[0.1, 0.2])[0]);
+}
+
+fn train_with_id(id: f64){
+    let mut data = DataSet::new();
+    data.push(&[id, 0.5], &[1.0]);
+
+    let mut nn = EmbeddingNetwork::new(&[(5, 2)], &[1, 3, 1]);
+    nn.train(&data, 1);
+}
+
+#[test]
+#[should_panic(expected = "not an ID in vocabulary of size 5")]
+fn category_out_of_vocabulary(){
+    train_with_id(5.0);
+}
+
+#[test]
+#[should_panic(expected = "not an ID in vocabulary of size 5")]
+fn negative_category(){
+    train_with_id(-1.0);
+}
+
+#[test]
+#[should_panic(expected = "not an ID in vocabulary of size 5")]
+fn fractional_category(){
+    train_with_id(2.5);
+}
@@ -0,0 +1,82 @@
+extern crate neuroflow;
+
+use std::fs::remove_file;
+
+use neuroflow::FeedForward;
+use neuroflow::data::DataSet;
+use neuroflow::activators::Type::Tanh;
+use neuroflow::ensemble::{Ensemble, Aggregation};
+use neuroflow::io::{save, load};
+
+
+#[test]
+fn averaging(){
+    let mut data = DataSet::new();
+    let mut x = -1.0;
+    while x <= 1.0 {
+        data.push(&[x], &[0.5 * x * x]);
+        x += 0.05;
+    }
+
+    let mut ensemble = Ensemble::new(&[1, 6, 1], 5);
+    ensemble.activation(Tanh)
+        .learning_rate(0.05)
+        .train(&data, 10_000);
+
+    let outputs = ensemble.outputs(&[0.5]);
+    let mean = outputs.iter().map(|y| y[0]).sum::<f64>() / 5.0;
+    assert!((ensemble.calc(&[0.5])[0] - mean).abs() < 1e-12);
+    assert!((mean - 0.125).abs() < 0.05);
+
+    /* members are uncertain far from training data */
+    let inside = ensemble.disagreement(&[0.5]);
+    let outside = ensemble.disagreement(&[10.0]);
+    println!("disagreement: {:.5} {:.5}", inside, outside);
+    assert!(inside < outside);
+}
+
+#[test]
+fn voting(){
+    let mut a = FeedForward::new(&[1, 1]);
+    let mut b = FeedForward::new(&[1, 1]);
+    let mut c = FeedForward::new(&[1, 1]);
+    for nn in [&mut a, &mut b, &mut c].iter_mut() {
+        nn.set_weights(1, &[vec![1.0]]).unwrap();
+    }
+    a.push_layer(2).set_weights(2, &[vec![1.0], vec![-1.0]]).unwrap();
+    b.push_layer(2).set_weights(2, &[vec![1.0], vec![-1.0]]).unwrap();
+    c.push_layer(2).set_weights(2, &[vec![-1.0], vec![1.0]]).unwrap();
+    for nn in [&mut a, &mut b, &mut c].iter_mut() {
+        nn.set_biases(1, &[0.0]).unwrap();
+        nn.set_biases(2, &[0.0, 0.0]).unwrap();
+    }
+
+    let mut ensemble = Ensemble::with_members(vec![a, b, c]);
+    ensemble.aggregation(Aggregation::Vote);
+
+    let votes = ensemble.calc(&[1.0]).to_vec();
+    assert!((votes[0] - 2.0 / 3.0).abs() < 1e-12);
+    assert!((votes[1] - 1.0 / 3.0).abs() < 1e-12);
+    assert!((ensemble.disagreement(&[1.0]) - 1.0 / 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn ensemble_saving(){
+    let mut ensemble = Ensemble::new(&[2, 3, 1], 4);
+    ensemble.activation(Tanh);
+    let expected = ensemble.calc(&[0.1, 0.2]).to_vec();
+
+    save(&mut ensemble, "ensemble_saving.flow").unwrap();
+    let mut restored: Ensemble = load("ensemble_saving.flow").unwrap();
+    remove_file("ensemble_saving.flow").unwrap();
+
+    assert_eq!(restored.len(), 4);
+    assert_eq!(restored.calc(&[0.1, 0.2]).to_vec(), expected);
+}
+
+#[test]
+#[should_panic(expected = "empty data")]
+fn training_on_empty_data(){
+    let mut ensemble = Ensemble::new(&[1, 3, 1], 2);
+    ensemble.train(&DataSet::new(), 10);
+}
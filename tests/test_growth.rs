@@ -0,0 +1,51 @@
+extern crate neuroflow;
+
+use neuroflow::FeedForward;
+use neuroflow::data::DataSet;
+use neuroflow::activators::Type::Tanh;
+use neuroflow::growth::Growth;
+
+
+#[test]
+fn bind_keeps_output(){
+    let mut nn = FeedForward::new(&[2, 3, 2, 1]);
+    let before = nn.calc(&[0.3, -0.7])[0];
+
+    nn.bind(1, 3);
+    nn.bind(2, 0);
+
+    assert_eq!(nn.architecture(), vec![2, 4, 3, 1]);
+    assert_eq!(nn.weights(2)[0].len(), 4);
+    assert_eq!(nn.weights(3)[0].len(), 3);
+    assert!((nn.calc(&[0.3, -0.7])[0] - before).abs() < 1e-12);
+
+    nn.fit(&[0.3, -0.7], &[0.5]);
+}
+
+#[test]
+fn dynamic_node_creation(){
+    let mut train = DataSet::new();
+    let mut validation = DataSet::new();
+    for i in 0..40 {
+        let x = i as f64 / 20.0 - 1.0;
+        train.push(&[x], &[(3.0 * x).sin() * 0.5]);
+        validation.push(&[x + 0.025], &[(3.0 * (x + 0.025)).sin() * 0.5]);
+    }
+
+    let mut nn = FeedForward::new(&[1, 1, 1]);
+    nn.activation(Tanh).learning_rate(0.05);
+
+    let errors = Growth::new()
+        .iterations(5_000)
+        .max_neurons(8)
+        .patience(2)
+        .run(&mut nn, &train, &validation);
+    println!("errors: {:?}, architecture: {:?}", errors, nn.architecture());
+
+    assert!(errors.len() > 1);
+    assert!(nn.architecture()[1] > 1);
+
+    let best = errors.iter().cloned().fold(f64::INFINITY, f64::min);
+    assert!(best < errors[0]);
+    assert!(best < 0.01);
+}
@@ -0,0 +1,83 @@
+extern crate neuroflow;
+
+use std::path::Path;
+use std::fs::remove_file;
+
+use neuroflow::hopfield::{Hopfield, Update};
+use neuroflow::io::{save, load};
+
+
+fn patterns() -> Vec<Vec<f64>> {
+    vec![
+        vec![1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0],
+        vec![0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0],
+    ]
+}
+
+fn bipolar(p: &[f64]) -> Vec<f64> {
+    p.iter().map(|v| if *v > 0.0 { 1.0 } else { -1.0 }).collect()
+}
+
+fn check_recall(update: Update){
+    let mut nn = Hopfield::new(16);
+    for p in patterns().iter(){
+        nn.store(p);
+    }
+
+    for p in patterns().iter(){
+        let mut corrupted = p.clone();
+        corrupted[0] = 1.0 - corrupted[0];
+        corrupted[7] = 1.0 - corrupted[7];
+
+        assert_eq!(nn.recall(&corrupted, update, 100), bipolar(p));
+    }
+}
+
+#[test]
+fn synchronous_recall(){
+    check_recall(Update::Synchronous);
+}
+
+#[test]
+fn asynchronous_recall(){
+    check_recall(Update::Asynchronous);
+}
+
+#[test]
+fn energy_decreases(){
+    let mut nn = Hopfield::new(16);
+    for p in patterns().iter(){
+        nn.store(p);
+    }
+
+    let mut corrupted = patterns()[0].clone();
+    corrupted[3] = 0.0;
+    let recalled = nn.recall(&corrupted, Update::Asynchronous, 100);
+
+    assert!(nn.energy(&recalled) < nn.energy(&corrupted));
+    assert_eq!(nn.energy(&recalled), nn.energy(&patterns()[0]));
+}
+
+#[test]
+fn capacity(){
+    let mut nn = Hopfield::new(100);
+    nn.store(&[1.0; 100]);
+
+    assert_eq!(nn.patterns(), 1);
+    assert!((nn.capacity() - 13.8).abs() < 1e-9);
+}
+
+#[test]
+fn saving_and_loading(){
+    let file_path = "testhopfield.nn";
+    let mut nn = Hopfield::new(16);
+    nn.store(&patterns()[0]);
+
+    save(&mut nn, file_path).unwrap();
+    let new_nn: Hopfield = load(file_path).unwrap();
+    remove_file(Path::new(file_path)).unwrap();
+
+    assert_eq!(new_nn.patterns(), 1);
+    assert_eq!(new_nn.recall(&patterns()[1], Update::Synchronous, 10),
+               nn.recall(&patterns()[1], Update::Synchronous, 10));
+}
@@ -30,10 +30,7 @@ fn saving_to_json(){
 
     match to_json(&nn) {
         Ok(s) => println!("{}", s),
-        Err(e) => {
-            println!("{:?}", e);
-            assert!(false);
-        }
+        Err(e) => panic!("{:?}", e)
     };
 }
 
@@ -97,9 +94,7 @@ fn loading_of_neural_net(){
         println!("for [{:.3}, {:.3}] -> [{:.3}], [{:.3}]",
                  v.0[0], v.0[1], res, res1);
 
-        if (res - res1).abs() > 0.1{
-            assert!(false);
-        }
+        assert!((res - res1).abs() <= 0.1);
     }
 
     let p = Path::new(file_path);
@@ -108,8 +103,5 @@ fn loading_of_neural_net(){
 
 #[test]
 fn load_not_existent_file(){
-    match load::<FeedForward>("testnonexistent.nn") {
-        Ok(_) => assert!(false),
-        Err(_) => assert!(true)
-    }
+    assert!(load::<FeedForward>("testnonexistent.nn").is_err());
 }
\ No newline at end of file
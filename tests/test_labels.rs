@@ -0,0 +1,77 @@
+extern crate neuroflow;
+
+use std::fs::remove_file;
+
+use neuroflow::FeedForward;
+use neuroflow::data::Extractable;
+use neuroflow::labels::{LabelEncoder, Classifier};
+use neuroflow::io::{save, load};
+
+
+#[test]
+fn encoding(){
+    let mut encoder = LabelEncoder::fit(&["cat", "dog", "cat"]);
+    assert_eq!(encoder.len(), 2);
+    assert_eq!(encoder.encode(&"dog"), Some(vec![0.0, 1.0]));
+    assert_eq!(encoder.encode(&"fox"), None);
+
+    assert_eq!(encoder.push(&"fox"), 2);
+    assert_eq!(encoder.push(&"cat"), 0);
+    assert_eq!(encoder.decode(&[0.2, 0.1, 0.5]), &"fox");
+
+    let data = encoder.data_set(&[vec![1.0], vec![2.0]], &["dog", "owl"]);
+    assert_eq!(encoder.classes(), &["cat", "dog", "fox", "owl"]);
+    assert_eq!(data.get(1).1, &vec![0.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn classification(){
+    let x = vec![vec![0.0, 0.1], vec![1.0, 0.9], vec![0.1, 0.0], vec![0.9, 1.0]];
+    let y = [10, 20, 10, 20];
+
+    let mut encoder = LabelEncoder::new();
+    let data = encoder.data_set(&x, &y);
+
+    let mut classifier = Classifier::new(&[2, 3], encoder);
+    classifier.network().learning_rate(0.05);
+    classifier.train(&data, 5_000);
+
+    assert_eq!(*classifier.predict(&[0.05, 0.05]), 10);
+    assert_eq!(*classifier.predict(&[0.95, 0.95]), 20);
+
+    save(&mut classifier, "classification.flow").unwrap();
+    let mut restored: Classifier<i32> = load("classification.flow").unwrap();
+    remove_file("classification.flow").unwrap();
+
+    assert_eq!(restored.encoder().classes(), &[10, 20]);
+    assert_eq!(*restored.predict(&[0.95, 0.95]), 20);
+}
+
+#[test]
+fn classifier_with_network(){
+    let encoder = LabelEncoder::fit(&['a', 'b', 'c']);
+    let mut classifier = Classifier::with_network(FeedForward::new(&[2, 4, 3]), encoder);
+    classifier.fit(&[0.0, 1.0], &'c');
+    assert_eq!(classifier.calc(&[0.0, 1.0]).len(), 3);
+}
+
+#[test]
+fn frozen_encoder(){
+    let encoder = LabelEncoder::fit(&["cat", "dog"]);
+    assert!(!encoder.is_frozen());
+
+    let classifier = Classifier::new(&[2, 3], encoder);
+    let mut encoder = classifier.encoder().clone();
+    assert!(encoder.is_frozen());
+
+    let data = encoder.data_set(&[vec![0.0, 1.0]], &["dog"]);
+    assert_eq!(data.get(0).1, &vec![0.0, 1.0]);
+}
+
+#[test]
+#[should_panic(expected = "encoder is frozen")]
+fn new_class_of_frozen_encoder(){
+    let mut encoder = LabelEncoder::fit(&["cat", "dog"]);
+    encoder.freeze();
+    encoder.data_set(&[vec![0.0, 1.0]], &["owl"]);
+}
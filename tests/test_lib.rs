@@ -10,6 +10,9 @@ use rand::distributions::Uniform;
 
 use neuroflow::activators;
 use neuroflow::estimators;
+use neuroflow::io::{save, load};
+
+use std::fs::remove_file;
 
 
 #[test]
@@ -39,13 +42,10 @@ fn xor(){
         println!("for [{:.3}, {:.3}], [{:.3}] -> [{:.3}]",
                  v.0[0], v.0[1], v.1[0], res);
 
-        if (res - v.1[0]).abs() > ALLOWED_ERROR {
-            assert!(false);
-        }
+        assert!((res - v.1[0]).abs() <= ALLOWED_ERROR);
     }
 
     println!("\nSpend time: {:.5}", (time::now_utc() - prev));
-    assert!(true);
 }
 
 #[test]
@@ -70,9 +70,7 @@ fn xor_through_data_set_and_train(){
         res = nn.calc(data.get(i).0)[0];
         d = data.get(i);
         println!("for [{:.3}, {:.3}], [{:.3}] -> [{:.3}]", d.0[0], d.0[1], d.1[0], res);
-        if (res - data.get(i).1[0]).abs() > ALLOWED_ERROR {
-            assert!(false);
-        }
+        assert!((res - data.get(i).1[0]).abs() <= ALLOWED_ERROR);
     }
 }
 
@@ -114,4 +112,154 @@ fn custom_activation(){
 fn widrows(){
     let w = estimators::widrows(&[2, 1], 0.1);
     assert_eq!(w, 90f64);
-}
\ No newline at end of file
+}
+
+#[test]
+fn hopfield_capacity(){
+    assert!((estimators::hopfield_capacity(1000) - 138.0).abs() < 1e-9);
+    assert!((estimators::hopfield_perfect_capacity(100) - 100.0 / (2.0 * 100f64.ln())).abs() < 1e-9);
+    assert_eq!(estimators::hopfield_perfect_capacity(1), 0.0);
+}
+
+#[test]
+fn introspection(){
+    let mut nn = FeedForward::new(&[3, 4, 2]);
+
+    assert_eq!(nn.architecture(), vec![3, 4, 2]);
+    assert_eq!(nn.layer_count(), 2);
+    assert_eq!(nn.weights(1).len(), 4);
+    assert_eq!(nn.weights(1)[0].len(), 3);
+    assert_eq!(nn.biases(2).len(), 2);
+
+    nn.calc(&[0.1, 0.2, 0.3]);
+    assert_eq!(nn.activations(1).len(), 4);
+    assert_eq!(nn.activations(2).to_vec(), nn.calc(&[0.1, 0.2, 0.3]).to_vec());
+}
+
+#[test]
+fn setting_weights(){
+    let mut nn = FeedForward::new(&[2, 2, 1]);
+
+    nn.set_weights(1, &[vec![0.0, 0.0], vec![0.0, 0.0]]).unwrap();
+    nn.set_biases(1, &[0.0, 0.0]).unwrap();
+    nn.set_weights(2, &[vec![1.0, 1.0]]).unwrap();
+    nn.set_biases(2, &[0.25]).unwrap();
+
+    assert_eq!(nn.weights(2), vec![vec![1.0, 1.0]]);
+    assert_eq!(nn.biases(2), vec![0.25]);
+    assert_eq!(nn.calc(&[3.0, -1.0])[0], 0.25);
+
+    assert!(nn.set_weights(3, &[vec![1.0, 1.0]]).is_err());
+    assert!(nn.set_weights(0, &[vec![1.0, 1.0]]).is_err());
+    assert!(nn.set_weights(2, &[vec![1.0, 1.0, 1.0]]).is_err());
+    assert!(nn.set_weights(1, &[vec![1.0, 1.0]]).is_err());
+    assert!(nn.set_biases(2, &[0.1, 0.2]).is_err());
+}
+
+#[test]
+fn freezing(){
+    let mut data = DataSet::new();
+    data.push(&[0.5, -0.5], &[0.3]);
+    data.push(&[-0.5, 0.5], &[-0.3]);
+
+    let mut nn = FeedForward::new(&[2, 3, 1]);
+    nn.freeze(1);
+
+    let (w1, w2) = (nn.weights(1), nn.weights(2));
+    nn.train(&data, 100);
+
+    assert_eq!(nn.weights(1), w1);
+    assert_ne!(nn.weights(2), w2);
+
+    nn.unfreeze(1);
+    nn.train(&data, 100);
+    assert_ne!(nn.weights(1), w1);
+}
+
+#[test]
+fn transfer(){
+    let mut nn = FeedForward::new(&[2, 4, 3, 1]);
+    nn.freeze(1);
+    save(&mut nn, "transfer.flow").unwrap();
+
+    let mut loaded: FeedForward = load("transfer.flow").unwrap();
+    remove_file("transfer.flow").unwrap();
+
+    assert!(!loaded.is_trainable(1));
+    assert!(loaded.is_trainable(2));
+
+    loaded.truncate(2).freeze(2).push_layer(2);
+    assert_eq!(loaded.architecture(), vec![2, 4, 3, 2]);
+
+    let hidden = loaded.weights(2);
+    loaded.fit(&[0.1, 0.2], &[0.3, 0.4]);
+    assert_eq!(loaded.weights(2), hidden);
+    assert_eq!(loaded.calc(&[0.1, 0.2]).len(), 2);
+}
+
+#[test]
+fn layers_insertion_and_removal(){
+    let mut nn = FeedForward::new(&[2, 3, 1]);
+    nn.activation(activators::Type::Relu);
+    nn.set_weights(1, &[vec![0.5, 0.5], vec![1.0, 0.0], vec![0.0, 1.0]]).unwrap();
+    nn.set_biases(1, &[0.1, 0.2, 0.3]).unwrap();
+
+    let before = nn.calc(&[0.4, 0.6])[0];
+
+    nn.insert_layer(2);
+    assert_eq!(nn.architecture(), vec![2, 3, 3, 1]);
+    assert!((nn.calc(&[0.4, 0.6])[0] - before).abs() < 0.1);
+
+    nn.remove_layer(2);
+    assert_eq!(nn.architecture(), vec![2, 3, 1]);
+    assert!((nn.calc(&[0.4, 0.6])[0] - before).abs() < 0.1);
+
+    nn.fit(&[0.4, 0.6], &[1.0]);
+}
+
+#[test]
+fn layer_removal_is_linear_approximation(){
+    let mut nn = FeedForward::new(&[2, 3, 3, 1]);
+    nn.set_weights(1, &[vec![0.5, -0.5], vec![1.0, 0.2], vec![-0.3, 0.8]]).unwrap();
+    nn.set_biases(1, &[0.1, -0.2, 0.3]).unwrap();
+    nn.set_weights(2, &[vec![0.04, -0.02, 0.01], vec![0.03, 0.05, -0.04], vec![-0.01, 0.02, 0.03]]).unwrap();
+    nn.set_biases(2, &[0.01, 0.0, -0.02]).unwrap();
+    nn.set_weights(3, &[vec![2.0, -1.0, 1.5]]).unwrap();
+    let x = [0.4, -0.6];
+
+    /* exact for linear activation */
+    let mut linear = nn.clone();
+    linear.custom_activation(|x| x, |_| 1.0);
+    let before = linear.calc(&x)[0];
+    linear.remove_layer(2);
+    assert!((linear.calc(&x)[0] - before).abs() < 1e-12);
+
+    /* close for small induced fields of removed layer */
+    nn.activation(activators::Type::Tanh);
+    let before = nn.calc(&x)[0];
+    let mut small = nn.clone();
+    small.remove_layer(2);
+    assert!((small.calc(&x)[0] - before).abs() < 1e-3);
+
+    /* activation of removed layer is lost for large induced fields */
+    nn.set_weights(2, &[vec![4.0, -2.0, 1.0], vec![3.0, 5.0, -4.0], vec![-1.0, 2.0, 3.0]]).unwrap();
+    let before = nn.calc(&x)[0];
+    nn.remove_layer(2);
+    assert!((nn.calc(&x)[0] - before).abs() > 0.1);
+}
+
+#[test]
+fn concatenation(){
+    let mut a = FeedForward::new(&[2, 4, 3]);
+    let mut b = FeedForward::new(&[3, 5, 1]);
+
+    let x = [0.3, -0.2];
+    let y = a.calc(&x).to_vec();
+    let expected = b.calc(&y).to_vec();
+
+    let mut nn = a.concat(&b).unwrap();
+    assert_eq!(nn.architecture(), vec![2, 4, 5, 1]);
+    assert!((nn.calc(&x)[0] - expected[0]).abs() < 1e-9);
+
+    assert!(b.concat(&a).is_err());
+}
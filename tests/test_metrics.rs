@@ -0,0 +1,129 @@
+extern crate neuroflow;
+
+use neuroflow::FeedForward;
+use neuroflow::data::DataSet;
+use neuroflow::metrics;
+use neuroflow::metrics::Average::{Macro, Micro};
+
+
+fn identity(x: f64) -> f64 {
+    x
+}
+
+fn one(_x: f64) -> f64 {
+    1.0
+}
+
+/// Network which returns its input
+fn network(n: usize) -> FeedForward {
+    let mut nn = FeedForward::new(&[n as i32, n as i32]);
+    nn.custom_activation(identity, one);
+
+    let w: Vec<Vec<f64>> = (0..n).map(|i| (0..n).map(|k| if i == k {1.0} else {0.0}).collect()).collect();
+    nn.set_weights(1, &w).unwrap();
+    nn.set_biases(1, &vec![0.0; n]).unwrap();
+    nn
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn regression(){
+    let mut nn = network(1);
+    let mut data = DataSet::new();
+    data.push(&[1.0], &[2.0]);
+    data.push(&[2.0], &[2.0]);
+    data.push(&[3.0], &[3.0]);
+
+    assert!(close(metrics::mse(&mut nn, &data), 1.0 / 3.0));
+    assert!(close(metrics::rmse(&mut nn, &data), (1.0f64 / 3.0).sqrt()));
+    assert!(close(metrics::mae(&mut nn, &data), 1.0 / 3.0));
+    assert!(close(metrics::r2(&mut nn, &data), -0.5));
+    assert!(close(metrics::mape(&mut nn, &data), 50.0 / 3.0));
+}
+
+#[test]
+fn binary_classification(){
+    let mut nn = network(1);
+    let mut data = DataSet::new();
+    for &(x, d) in [(0.1, 0.0), (0.4, 1.0), (0.6, 1.0), (0.9, 1.0), (0.7, 0.0)].iter() {
+        data.push(&[x], &[d]);
+    }
+
+    assert_eq!(metrics::confusion_matrix(&mut nn, &data), vec![vec![1, 1], vec![1, 2]]);
+    assert!(close(metrics::accuracy(&mut nn, &data), 0.6));
+    assert!(close(metrics::precision(&mut nn, &data, Macro), (0.5 + 2.0 / 3.0) / 2.0));
+    assert!(close(metrics::precision(&mut nn, &data, Micro), 0.6));
+    assert!(close(metrics::recall(&mut nn, &data, Macro), (0.5 + 2.0 / 3.0) / 2.0));
+    assert!(close(metrics::f1(&mut nn, &data, Micro), 0.6));
+
+    let loss = -(0.9f64.ln() + 0.4f64.ln() + 0.6f64.ln() + 0.9f64.ln() + 0.3f64.ln()) / 5.0;
+    assert!(close(metrics::log_loss(&mut nn, &data), loss));
+
+    assert!(close(metrics::roc_auc(&mut nn, &data), 4.0 / 6.0));
+    assert!(close(metrics::pr_auc(&mut nn, &data), 1.0 / 3.0 + 2.0 / 9.0 + 1.0 / 4.0));
+}
+
+#[test]
+fn multiclass_classification(){
+    let mut nn = network(3);
+    let mut data = DataSet::new();
+    data.push(&[0.8, 0.1, 0.1], &[1.0, 0.0, 0.0]);
+    data.push(&[0.1, 0.8, 0.1], &[0.0, 1.0, 0.0]);
+    data.push(&[0.1, 0.1, 0.8], &[0.0, 0.0, 1.0]);
+    data.push(&[0.6, 0.3, 0.1], &[0.0, 1.0, 0.0]);
+
+    assert_eq!(metrics::confusion_matrix(&mut nn, &data),
+               vec![vec![1, 0, 0], vec![1, 1, 0], vec![0, 0, 1]]);
+    assert!(close(metrics::accuracy(&mut nn, &data), 0.75));
+    assert!(close(metrics::recall(&mut nn, &data, Macro), (1.0 + 0.5 + 1.0) / 3.0));
+    assert!(close(metrics::precision(&mut nn, &data, Macro), (0.5 + 1.0 + 1.0) / 3.0));
+
+    let auc = metrics::roc_auc(&mut nn, &data);
+    assert!(auc > 0.5 && auc <= 1.0);
+    assert!(close(metrics::log_loss(&mut nn, &data), -(0.8f64.ln() * 3.0 + 0.3f64.ln()) / 4.0));
+}
+
+#[test]
+fn empty_data(){
+    let mut nn = network(2);
+    let data = DataSet::new();
+
+    assert!(metrics::mse(&mut nn, &data).is_nan());
+    assert!(metrics::r2(&mut nn, &data).is_nan());
+    assert!(metrics::mape(&mut nn, &data).is_nan());
+    assert!(metrics::confusion_matrix(&mut nn, &data).is_empty());
+    assert!(metrics::accuracy(&mut nn, &data).is_nan());
+    assert!(metrics::precision(&mut nn, &data, Micro).is_nan());
+    assert!(metrics::f1(&mut nn, &data, Macro).is_nan());
+    assert!(metrics::roc_auc(&mut nn, &data).is_nan());
+    assert!(metrics::pr_auc(&mut nn, &data).is_nan());
+}
+
+#[test]
+fn mape_of_zero_targets(){
+    let mut nn = network(1);
+    let mut data = DataSet::new();
+    data.push(&[1.0], &[0.0]);
+    data.push(&[2.0], &[4.0]);
+
+    assert!(close(metrics::mape(&mut nn, &data), 50.0));
+
+    let mut zeros = DataSet::new();
+    zeros.push(&[1.0], &[0.0]);
+    assert!(metrics::mape(&mut nn, &zeros).is_nan());
+}
+
+#[test]
+fn nan_scores(){
+    let mut nn = network(1);
+    let mut data = DataSet::new();
+    data.push(&[f64::NAN], &[1.0]);
+    data.push(&[0.2], &[0.0]);
+    data.push(&[0.8], &[1.0]);
+
+    assert!(close(metrics::roc_auc(&mut nn, &data), 1.0));
+    assert!(close(metrics::pr_auc(&mut nn, &data), 1.0));
+}
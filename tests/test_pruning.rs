@@ -0,0 +1,116 @@
+extern crate neuroflow;
+
+use neuroflow::FeedForward;
+use neuroflow::data::DataSet;
+use neuroflow::activators::Type::Tanh;
+use neuroflow::pruning;
+
+
+#[test]
+fn unbind_keeps_network_consistent(){
+    let mut nn = FeedForward::new(&[2, 4, 3, 1]);
+
+    nn.unbind(1, 2);
+    nn.unbind(2, 0);
+
+    assert_eq!(nn.architecture(), vec![2, 3, 2, 1]);
+    assert_eq!(nn.weights(2)[0].len(), 3);
+    assert_eq!(nn.weights(3)[0].len(), 2);
+
+    nn.calc(&[0.5, -0.5]);
+    nn.fit(&[0.5, -0.5], &[0.1]);
+}
+
+#[test]
+fn magnitude_pruning(){
+    let mut data = DataSet::new();
+    for i in 0..41 {
+        let x = -1.0 + i as f64 * 0.05;
+        data.push(&[x], &[(3.0 * x).sin() * 0.5]);
+    }
+
+    let mut nn = FeedForward::new(&[1, 10, 1]);
+    nn.activation(Tanh).learning_rate(0.05);
+
+    pruning::magnitude(&mut nn, 0.3);
+    assert!((pruning::sparsity(&nn) - 0.3).abs() < 0.01);
+
+    let pruned: Vec<usize> = nn.weights(1).iter().enumerate()
+        .filter(|&(_, w)| w[0] == 0.0).map(|(i, _)| i).collect();
+
+    nn.train(&data, 5_000);
+
+    for i in pruned {
+        assert_eq!(nn.weights(1)[i][0], 0.0);
+    }
+
+    let before = pruning::sparsity(&nn);
+    pruning::magnitude_layer(&mut nn, 2, 0.5);
+    assert!(pruning::sparsity(&nn) > before);
+    assert!(nn.weights(2)[0].iter().filter(|w| **w == 0.0).count() >= 5);
+
+    pruning::clear(&mut nn);
+    assert_eq!(pruning::sparsity(&nn), 0.0);
+}
+
+#[test]
+fn neuron_pruning(){
+    let mut data = DataSet::new();
+    for i in 0..41 {
+        let x = -1.0 + i as f64 * 0.05;
+        data.push(&[x], &[(3.0 * x).sin() * 0.5]);
+    }
+
+    let mut nn = FeedForward::new(&[1, 8, 1]);
+    nn.activation(Tanh).learning_rate(0.05);
+    nn.train(&data, 10_000);
+
+    let sens = pruning::sensitivity(&mut nn, &data);
+    assert_eq!(sens.len(), 8);
+    for i in 1..sens.len() {
+        assert!(sens[i - 1].score <= sens[i].score);
+    }
+
+    let removed = pruning::prune_neurons(&mut nn, &data, 10);
+    assert_eq!(removed.len(), 7);
+    assert_eq!(nn.architecture(), vec![1, 1, 1]);
+}
+
+#[test]
+fn iterative_pruning(){
+    let mut data = DataSet::new();
+    for i in 0..41 {
+        let x = -1.0 + i as f64 * 0.05;
+        data.push(&[x], &[(3.0 * x).sin() * 0.5]);
+    }
+
+    let mut nn = FeedForward::new(&[1, 10, 1]);
+    nn.activation(Tanh).learning_rate(0.05);
+    nn.train(&data, 10_000);
+
+    let errors = pruning::iterative(&mut nn, &data, 3, 0.2, 3_000);
+    println!("errors: {:?}", errors);
+
+    assert_eq!(errors.len(), 3);
+    assert!((pruning::sparsity(&nn) - (1.0 - 0.8f64.powi(3))).abs() < 0.05);
+    assert!(errors[2] < 0.01);
+}
+
+#[test]
+fn nan_weights_and_empty_data(){
+    let mut nn = FeedForward::new(&[1, 4, 1]);
+    nn.set_weights(1, &[vec![f64::NAN], vec![0.1], vec![0.2], vec![0.3]]).unwrap();
+
+    pruning::magnitude_layer(&mut nn, 1, 0.5);
+    assert!(nn.weights(1)[0][0].is_nan());
+    assert_eq!(nn.weights(1)[1][0], 0.0);
+    assert_eq!(nn.weights(1)[2][0], 0.0);
+
+    let empty = DataSet::new();
+    let sens = pruning::sensitivity(&mut nn, &empty);
+    assert_eq!(sens.len(), 4);
+    assert!(sens.iter().all(|s| s.score.is_nan()));
+
+    assert!(pruning::prune_neurons(&mut nn, &empty, 2).is_empty());
+    assert_eq!(nn.architecture(), vec![1, 4, 1]);
+}
@@ -0,0 +1,52 @@
+extern crate neuroflow;
+
+use std::fs::remove_file;
+
+use neuroflow::FeedForward;
+use neuroflow::data::DataSet;
+use neuroflow::activators::Type::Tanh;
+use neuroflow::quantization::Quantized;
+use neuroflow::io::{save, load};
+
+
+#[test]
+fn quantization(){
+    let mut data = DataSet::new();
+    for i in 0..41 {
+        let x = -1.0 + i as f64 * 0.05;
+        data.push(&[x, 0.5 * x], &[(3.0 * x).sin() * 0.5]);
+    }
+
+    let mut nn = FeedForward::new(&[2, 10, 1]);
+    nn.activation(Tanh).learning_rate(0.05);
+    nn.train(&data, 10_000);
+
+    let mut q = Quantized::new(&mut nn, &data);
+    let report = q.compare(&mut nn, &data);
+    println!("{:?}", report);
+
+    assert!(report.max_deviation < 0.05);
+    assert!(report.loss() < 0.001);
+    assert_eq!(q.size(), 2 * 10 + 4 * 10 + 10 + 4);
+}
+
+#[test]
+fn quantized_saving(){
+    let mut data = DataSet::new();
+    for i in 0..41 {
+        let x = -1.0 + i as f64 * 0.05;
+        data.push(&[x, 0.5 * x], &[(3.0 * x).sin() * 0.5]);
+    }
+
+    let mut nn = FeedForward::new(&[2, 6, 3, 1]);
+    nn.activation(Tanh);
+
+    let mut q = Quantized::new(&mut nn, &data);
+    let expected = q.calc(&[0.2, 0.1]).to_vec();
+
+    save(&mut q, "quantized_saving.flow").unwrap();
+    let mut restored: Quantized = load("quantized_saving.flow").unwrap();
+    remove_file("quantized_saving.flow").unwrap();
+
+    assert_eq!(restored.calc(&[0.2, 0.1]).to_vec(), expected);
+}
@@ -0,0 +1,85 @@
+extern crate neuroflow;
+
+use std::path::Path;
+use std::fs::remove_file;
+
+use neuroflow::rbf::{RadialBasis, kmeans};
+use neuroflow::data::{DataSet, Extractable};
+use neuroflow::io::{save, load};
+
+
+#[test]
+fn least_squares(){
+    const ALLOWED_ERROR: f64 = 0.05;
+    let mut data = DataSet::new();
+    for i in 0..61 {
+        let x = -3.0 + i as f64 * 0.1;
+        data.push(&[x], &[x.sin()]);
+    }
+
+    let centers: Vec<Vec<f64>> = (0..12).map(|i| vec![-3.0 + i as f64 * 6.0 / 11.0]).collect();
+    let mut nn = RadialBasis::with_centers(&centers, &[0.6; 12], 1);
+
+    nn.solve(&data, 1e-6).unwrap();
+
+    for i in 0..data.len(){
+        let (x, y) = data.get(i);
+        assert!((nn.calc(x)[0] - y[0]).abs() < ALLOWED_ERROR);
+    }
+}
+
+#[test]
+fn gradient_descent(){
+    const ALLOWED_ERROR: f64 = 0.1;
+    let mut data = DataSet::new();
+    for i in 0..61 {
+        let x = -3.0 + i as f64 * 0.1;
+        data.push(&[x], &[x.sin()]);
+    }
+
+    let mut nn = RadialBasis::new(&data, 12);
+
+    nn.learning_rate(0.1)
+        .train(&data, 30_000);
+
+    for i in 0..data.len(){
+        let (x, y) = data.get(i);
+        assert!((nn.calc(x)[0] - y[0]).abs() < ALLOWED_ERROR);
+    }
+}
+
+#[test]
+fn clusters(){
+    let mut data = DataSet::new();
+    for i in 0..10{
+        let d = i as f64 * 0.01;
+        data.push(&[d, -d], &[]);
+        data.push(&[5.0 + d, 5.0 - d], &[]);
+    }
+
+    let mut centers = kmeans(&data, 2, 100);
+    centers.sort_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+
+    assert!((centers[0][0] - 0.045).abs() < 1e-9);
+    assert!((centers[1][0] - 5.045).abs() < 1e-9);
+}
+
+#[test]
+fn saving_and_loading(){
+    let file_path = "testrbf.nn";
+    let mut data = DataSet::new();
+    for i in 0..61 {
+        let x = -3.0 + i as f64 * 0.1;
+        data.push(&[x], &[x.sin()]);
+    }
+
+    let mut nn = RadialBasis::new(&data, 5);
+    nn.solve(&data, 1e-6).unwrap();
+
+    save(&mut nn, file_path).unwrap();
+    let mut new_nn: RadialBasis = load(file_path).unwrap();
+    remove_file(Path::new(file_path)).unwrap();
+
+    assert_eq!(nn.centers(), new_nn.centers());
+    assert_eq!(nn.calc(&[0.3]).to_vec(), new_nn.calc(&[0.3]).to_vec());
+}
@@ -0,0 +1,119 @@
+extern crate neuroflow;
+extern crate rand;
+
+use std::path::Path;
+use std::fs::remove_file;
+
+use neuroflow::recurrent::{Recurrent, Cell};
+use neuroflow::activators::Type;
+use neuroflow::data::{SequenceSet, SequenceExtractable};
+use neuroflow::io::{save, load};
+
+
+fn sine_forecast(cell: Cell) -> f64 {
+    let series: Vec<f64> = (0..200).map(|i| (i as f64 * 0.2).sin()).collect();
+    let data = SequenceSet::from_series(&series, 12, 1);
+    let mut nn = Recurrent::new(cell, &[1, 8, 1]);
+
+    nn.learning_rate(0.02)
+        .momentum(0.5)
+        .bptt(6)
+        .train(&data, 3_000);
+
+    let mut max: f64 = 0.0;
+    for i in 0..data.len(){
+        let (x, y) = data.get(i);
+        let res = nn.calc(x);
+        max = max.max((res[res.len() - 1][0] - y[0][0]).abs());
+    }
+    println!("{:?}: max error {:.4}", cell, max);
+    max
+}
+
+#[test]
+fn elman_forecast(){
+    assert!(sine_forecast(Cell::Elman) < 0.1);
+}
+
+#[test]
+fn lstm_forecast(){
+    assert!(sine_forecast(Cell::Lstm) < 0.1);
+}
+
+#[test]
+fn gru_forecast(){
+    assert!(sine_forecast(Cell::Gru) < 0.1);
+}
+
+#[test]
+fn delayed_echo(){
+    // Output at each step is the input of the previous step
+    const ALLOWED_ERROR: f64 = 0.15;
+    let mut data = SequenceSet::new();
+    for _ in 0..50{
+        let x: Vec<Vec<f64>> = (0..8).map(|_| vec![rand_sign()]).collect();
+        let mut y = vec![vec![0.0]];
+        for t in 1..x.len(){
+            y.push(x[t - 1].clone());
+        }
+        data.push(&x, &y);
+    }
+
+    let mut nn = Recurrent::new(Cell::Lstm, &[1, 6, 6, 1]);
+    nn.learning_rate(0.02)
+        .momentum(0.5)
+        .train(&data, 3_000);
+
+    for i in 0..data.len(){
+        let (x, y) = data.get(i);
+        let res = nn.calc(x);
+        for t in 1..y.len(){
+            assert!((res[t][0] - y[t][0]).abs() < ALLOWED_ERROR);
+        }
+    }
+}
+
+#[test]
+fn saving_and_loading(){
+    let file_path = "testrecurrent.nn";
+    let mut nn = Recurrent::new(Cell::Gru, &[2, 4, 3, 2]);
+    let xs = vec![vec![0.5, -0.3], vec![0.1, 0.9], vec![-0.7, 0.2]];
+
+    nn.fit(&xs, &[vec![0.1, 0.2]]);
+    save(&mut nn, file_path).unwrap();
+
+    let mut new_nn: Recurrent = load(file_path).unwrap();
+    remove_file(Path::new(file_path)).unwrap();
+
+    let res = nn.calc(&xs);
+    let res1 = new_nn.calc(&xs);
+    for t in 0..res.len(){
+        for i in 0..res[t].len(){
+            assert_eq!(res[t][i], res1[t][i]);
+        }
+    }
+}
+
+#[test]
+fn step_by_step(){
+    let mut nn = Recurrent::new(Cell::Elman, &[1, 4, 1]);
+    let xs = vec![vec![0.3], vec![-0.1], vec![0.8]];
+
+    let res = nn.calc(&xs);
+
+    nn.reset();
+    for t in 0..xs.len(){
+        assert_eq!(nn.step(&xs[t]), res[t]);
+    }
+}
+
+#[test]
+#[should_panic(expected = "not supported by recurrent cells")]
+fn parametric_activation(){
+    let mut nn = Recurrent::new(Cell::Elman, &[1, 4, 1]);
+    nn.activation(Type::PRelu);
+}
+
+fn rand_sign() -> f64 {
+    if rand::random::<bool>() { 1.0 } else { -1.0 }
+}
@@ -0,0 +1,94 @@
+extern crate neuroflow;
+
+use neuroflow::FeedForward;
+use neuroflow::data::{DataSet, Extractable};
+use neuroflow::metrics::mse;
+use neuroflow::activators::Type::{Tanh, Relu};
+use neuroflow::search::{Search, Space, Strategy};
+
+
+#[test]
+fn grid_search(){
+    let mut data = DataSet::new();
+    for i in 0..100 {
+        let x = -1.0 + i as f64 * 0.02;
+        data.push(&[x], &[0.5 * x * x]);
+    }
+
+    let mut space = Space::new();
+    space.architecture(&[1, 6, 1])
+        .architecture(&[1, 1])
+        .learning_rates(&[0.05, 1e-6])
+        .activations(&[Tanh, Relu]);
+
+    assert_eq!(space.grid().len(), 8);
+
+    let mut results = Search::new(space)
+        .iterations(5_000)
+        .proportion(0.25)
+        .threads(4)
+        .run(&data, mse);
+
+    assert_eq!(results.trials.len(), 8);
+    for i in 1..results.trials.len() {
+        assert!(results.trials[i - 1].score <= results.trials[i].score);
+    }
+
+    let best = &results.trials[0];
+    println!("{:?}", best);
+    assert_eq!(best.params.architecture, vec![1, 6, 1]);
+    assert_eq!(best.params.learning_rate, 0.05);
+
+    /* the best network is trained and data of caller is not divided */
+    assert_eq!(data.len(), 100);
+    assert_eq!(data.test_set().len(), 0);
+    assert!(mse(&mut results.best, &data) < 0.01);
+}
+
+#[test]
+fn random_search(){
+    let mut data = DataSet::new();
+    for i in 0..100 {
+        let x = -1.0 + i as f64 * 0.02;
+        data.push(&[x], &[0.5 * x * x]);
+    }
+
+    let mut space = Space::new();
+    space.architecture(&[1, 4, 1])
+        .learning_rates(&[0.01, 0.05])
+        .momenta(&[0.0, 0.1]);
+
+    let results = Search::new(space)
+        .strategy(Strategy::Random(3))
+        .iterations(1_000)
+        .maximize(true)
+        .run(&data, |nn: &mut FeedForward, test: &DataSet| -mse(nn, test));
+
+    assert_eq!(results.trials.len(), 3);
+    assert!(results.trials[0].score >= results.trials[2].score);
+}
+
+#[test]
+fn diverged_trials(){
+    let mut data = DataSet::new();
+    for i in 0..100 {
+        let x = -1.0 + i as f64 * 0.02;
+        data.push(&[x], &[0.5 * x * x]);
+    }
+
+    for &maximize in [false, true].iter() {
+        let mut space = Space::new();
+        space.architecture(&[1, 6, 1])
+            .learning_rates(&[0.05, f64::INFINITY])
+            .activations(&[Tanh]);
+
+        let results = Search::new(space)
+            .iterations(2_000)
+            .maximize(maximize)
+            .run(&data, |nn: &mut FeedForward, test: &DataSet| if maximize {-mse(nn, test)} else {mse(nn, test)});
+
+        assert_eq!(results.trials[0].params.learning_rate, 0.05, "maximize: {}", maximize);
+        assert!(!results.trials[0].score.is_nan());
+        assert!(results.trials[1].score.is_nan());
+    }
+}
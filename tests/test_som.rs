@@ -0,0 +1,90 @@
+extern crate neuroflow;
+
+use std::path::Path;
+use std::fs::{remove_file, read_to_string};
+
+use neuroflow::som::{SelfOrganizingMap, Topology, Neighbourhood, Decay};
+use neuroflow::data::DataSet;
+use neuroflow::io::{save, load};
+
+
+fn clusters() -> DataSet {
+    let mut data = DataSet::new();
+    for i in 0..20{
+        let d = (i % 5) as f64 * 0.02;
+        data.push(&[0.1 + d, 0.1 - d], &[]);
+        data.push(&[0.9 - d, 0.9 + d], &[]);
+    }
+    data
+}
+
+fn check_clusters(topology: Topology, neighbourhood: Neighbourhood, decay: Decay){
+    let data = clusters();
+    let mut som = SelfOrganizingMap::new(4, 4, 2);
+
+    som.topology(topology)
+        .neighbourhood(neighbourhood)
+        .decay(decay)
+        .learning_rate(0.5)
+        .init(&data)
+        .train(&data, 2_000);
+
+    assert_ne!(som.winner(&[0.1, 0.1]), som.winner(&[0.9, 0.9]));
+    assert!(som.quantization_error(&data) < 0.1);
+}
+
+#[test]
+fn rectangular_gaussian(){
+    check_clusters(Topology::Rectangular, Neighbourhood::Gaussian, Decay::Exponential);
+}
+
+#[test]
+fn hexagonal_bubble(){
+    check_clusters(Topology::Hexagonal, Neighbourhood::Bubble, Decay::Linear);
+}
+
+#[test]
+fn mexican_hat(){
+    check_clusters(Topology::Rectangular, Neighbourhood::MexicanHat, Decay::InverseTime);
+}
+
+#[test]
+fn u_matrix_export(){
+    let file_path = "testumatrix.csv";
+    let data = clusters();
+    let mut som = SelfOrganizingMap::new(3, 5, 2);
+    som.train(&data, 500);
+
+    let u = som.u_matrix();
+    assert_eq!(u.len(), 3);
+    assert_eq!(u[0].len(), 5);
+
+    som.save_u_matrix(file_path).unwrap();
+    let content = read_to_string(file_path).unwrap();
+    remove_file(Path::new(file_path)).unwrap();
+
+    assert_eq!(content.lines().count(), 3);
+    assert_eq!(content.lines().next().unwrap().split(',').count(), 5);
+}
+
+#[test]
+fn saving_and_loading(){
+    let file_path = "testsom.nn";
+    let mut som = SelfOrganizingMap::new(3, 3, 2);
+    som.topology(Topology::Hexagonal);
+
+    save(&mut som, file_path).unwrap();
+    let new_som: SelfOrganizingMap = load(file_path).unwrap();
+    remove_file(Path::new(file_path)).unwrap();
+
+    assert_eq!(som.size(), new_som.size());
+    assert_eq!(som.weights(1, 2), new_som.weights(1, 2));
+    assert_eq!(som.u_matrix(), new_som.u_matrix());
+}
+
+#[test]
+#[should_panic(expected = "at least one vector")]
+fn training_on_empty_data(){
+    let mut som = SelfOrganizingMap::new(2, 2, 2);
+    som.train(&DataSet::new(), 10);
+}
@@ -0,0 +1,70 @@
+extern crate neuroflow;
+
+use neuroflow::FeedForward;
+use neuroflow::data::{DataSet, Extractable};
+use neuroflow::metrics::mse;
+use neuroflow::activators::Type::Tanh;
+use neuroflow::validation;
+
+
+fn factory() -> FeedForward {
+    let mut nn = FeedForward::new(&[1, 6, 1]);
+    nn.activation(Tanh).learning_rate(0.05);
+    nn
+}
+
+#[test]
+fn k_fold(){
+    let mut data = DataSet::new();
+    for i in 0..41 {
+        let x = -1.0 + i as f64 * 0.05;
+        data.push(&[x], &[0.5 * x * x]);
+    }
+
+    let mut sizes = Vec::new();
+
+    let scores = validation::k_fold(&data, 4, 5_000, factory, |nn: &mut FeedForward, test: &DataSet| {
+        sizes.push(test.len());
+        mse(nn, test)
+    });
+    println!("{:?}: {:.5} ± {:.5}", scores.folds, scores.mean(), scores.std());
+
+    assert_eq!(scores.folds.len(), 4);
+    assert_eq!(sizes.iter().sum::<usize>(), data.len());
+    assert!(sizes.iter().all(|s| *s == 10 || *s == 11));
+    assert!(scores.mean() < 0.01);
+    assert!(scores.std() >= 0.0);
+}
+
+#[test]
+fn repeated_and_leave_one_out(){
+    let mut data = DataSet::new();
+    for i in 0..41 {
+        let x = -1.0 + i as f64 * 0.05;
+        data.push(&[x], &[0.5 * x * x]);
+    }
+
+    let mut created = 0;
+
+    let scores = validation::repeated_k_fold(&data, 3, 2, 1_000, || {created += 1; factory()}, mse);
+    assert_eq!(scores.folds.len(), 6);
+    assert_eq!(created, 6);
+
+    let scores = validation::leave_one_out(&data, 500, factory, mse);
+    assert_eq!(scores.folds.len(), data.len());
+}
+
+#[test]
+fn scores_statistics(){
+    let scores = validation::Scores {folds: vec![1.0, 2.0, 3.0]};
+    assert_eq!(scores.mean(), 2.0);
+    assert_eq!(scores.std(), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "at least two elements")]
+fn leave_one_out_of_single_element(){
+    let mut data = DataSet::new();
+    data.push(&[0.1], &[0.2]);
+    validation::leave_one_out(&data, 10, factory, mse);
+}